@@ -0,0 +1,225 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine, MeshInstance3D},
+    prelude::*,
+};
+
+use super::{warn_on_period, warn_on_recovery, AnimatorError, InterpolationMode};
+use crate::second_order_systems::*;
+
+/// The range of the blend shape weights the inspector of MeshInstance3D allows.
+const BLEND_SHAPE_VALUE_RANGE: (f32, f32) = (-16.0, 16.0);
+
+/// Animates a blend shape weight of the follower mesh toward the weight of the same
+/// blend shape of the target mesh, e.g. for facial animation.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorBlendShape {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<MeshInstance3D>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<MeshInstance3D>>,
+    #[export]
+    #[var(get, set = set_blend_shape_name)]
+    blend_shape_name: StringName,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+    // The fastest the follower may change, per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_speed)]
+    max_speed: f32,
+
+    system: SecondOrderSystemFloat,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorBlendShape {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<MeshInstance3D>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<MeshInstance3D>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_blend_shape_name(&mut self, value: StringName) {
+        self.blend_shape_name = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_speed(&mut self, value: f32) {
+        self.max_speed = value.max(0.0);
+        self.system.update_max_speed(self.max_speed);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let previous = get_blend_shape_value(self.target.as_ref().unwrap(), &self.blend_shape_name);
+        let current =
+            get_blend_shape_value(self.follower.as_ref().unwrap(), &self.blend_shape_name);
+
+        self.system
+            .update_initial_values(previous.unwrap(), current.unwrap(), 0.0);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let input = get_blend_shape_value(self.target.as_ref().unwrap(), &self.blend_shape_name);
+        let output = self
+            .system
+            .update(input.unwrap(), delta)
+            .clamp(BLEND_SHAPE_VALUE_RANGE.0, BLEND_SHAPE_VALUE_RANGE.1);
+        warn_on_recovery(self.system.take_recovered());
+
+        let follower = self.follower.as_mut().unwrap();
+        let index = follower.find_blend_shape_by_name(self.blend_shape_name.clone());
+        follower.set_blend_shape_value(index, output);
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        let Some(target) = self.target.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        };
+        let Some(follower) = self.follower.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        };
+
+        if self.blend_shape_name.is_empty() {
+            return Err(AnimatorError::PropertyNotSpecified("blend_shape_name"));
+        }
+        if get_blend_shape_value(target, &self.blend_shape_name).is_none() {
+            return Err(AnimatorError::BlendShapeNotFound(
+                "target",
+                self.blend_shape_name.to_string(),
+            ));
+        }
+        if get_blend_shape_value(follower, &self.blend_shape_name).is_none() {
+            return Err(AnimatorError::BlendShapeNotFound(
+                "follower",
+                self.blend_shape_name.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorBlendShape, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        blend_shape_name: StringName::default(),
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        max_speed: 0.0,
+        system: SecondOrderSystemFloat::new(period, damping, response),
+        base,
+    }
+});
+
+/// Reads the weight of the named blend shape. Returns None if the mesh has no such blend shape.
+#[inline]
+fn get_blend_shape_value(mesh: &Gd<MeshInstance3D>, name: &StringName) -> Option<f32> {
+    let index = mesh.find_blend_shape_by_name(name.clone());
+
+    if index < 0 {
+        return None;
+    }
+
+    Some(mesh.get_blend_shape_value(index))
+}
@@ -0,0 +1,123 @@
+// The engine callbacks of the animators that are written by hand instead of generated by
+// generate_animator, as their fields and systems don't fit it. The animator must have
+// the active, run_in_editor, interpolation_mode, is_editor and has_warned fields, and the _validate,
+// _update_initial_values and _update methods.
+// Parameters:
+// $node_name: The name of the animator class.
+// $base, $init: The body of INode::init, receiving the base.
+macro_rules! impl_animator_callbacks {
+    ($node_name:ident, |$base:ident| $init:block) => {
+        impl $node_name {
+            /// Validates on the per-frame paths, allowing a new warning once the animator is valid again.
+            fn _validate_and_track(&mut self) -> Result<(), AnimatorError> {
+                let result = self._validate();
+                if result.is_ok() {
+                    self.has_warned = false;
+                }
+
+                result
+            }
+
+            /// Warns only the first time the validation fails, instead of every frame.
+            fn _warn_once(&mut self, err: AnimatorError) {
+                if !self.has_warned {
+                    godot_warn!("Animator error: {}", err);
+                    self.has_warned = true;
+                }
+            }
+
+            fn _proceed_notification(
+                &mut self,
+                notification: NodeNotification,
+            ) -> Result<(), AnimatorError> {
+                if let NodeNotification::EnterTree | NodeNotification::Ready = notification {
+                    self.is_editor = Engine::singleton().is_editor_hint();
+                }
+
+                if !self.active || (self.is_editor && !self.run_in_editor) {
+                    return Ok(());
+                }
+
+                match (notification, self.interpolation_mode) {
+                    (NodeNotification::Process, InterpolationMode::Process) => {
+                        self._validate_and_track()?;
+
+                        let delta = self.base().get_process_delta_time();
+                        self._update(delta);
+                    }
+                    (NodeNotification::PhysicsProcess, InterpolationMode::Physics) => {
+                        self._validate_and_track()?;
+
+                        let delta = self.base().get_physics_process_delta_time();
+                        self._update(delta);
+                    }
+                    (NodeNotification::Ready, _) => {
+                        self._validate_and_track()?;
+                        self.base_mut().set_process(true);
+                        self._update_initial_values();
+                    }
+                    _ => {}
+                }
+
+                Ok(())
+            }
+        }
+
+        #[godot_api]
+        impl INode for $node_name {
+            fn init($base: Base<Node>) -> Self $init
+
+            fn get_configuration_warnings(&self) -> PackedStringArray {
+                let mut warnings = PackedStringArray::new();
+
+                if let Err(err) = self._validate() {
+                    warnings.push(err.to_string().into());
+                }
+
+                warnings
+            }
+
+            // See the generate_animator macro on why both the process methods and on_notification are used.
+            // The systems are only stepped in the process of the interpolation mode,
+            // otherwise a frame with a physics step would step them twice.
+
+            fn process(&mut self, delta: f64) {
+                if !self.active
+                    || self.interpolation_mode != InterpolationMode::Process
+                    || (self.is_editor && !self.run_in_editor)
+                {
+                    return;
+                }
+
+                if let Err(err) = self._validate_and_track() {
+                    self._warn_once(err);
+                    return;
+                }
+
+                self._update(delta);
+            }
+
+            fn physics_process(&mut self, delta: f64) {
+                if !self.active
+                    || self.interpolation_mode != InterpolationMode::Physics
+                    || (self.is_editor && !self.run_in_editor)
+                {
+                    return;
+                }
+
+                if let Err(err) = self._validate_and_track() {
+                    self._warn_once(err);
+                    return;
+                }
+
+                self._update(delta);
+            }
+
+            fn on_notification(&mut self, notification: NodeNotification) {
+                if let Err(err) = self._proceed_notification(notification) {
+                    self._warn_once(err);
+                }
+            }
+        }
+    };
+}
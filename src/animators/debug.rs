@@ -0,0 +1,121 @@
+use godot::{
+    engine::{
+        base_material_3d, mesh, Control, ImmediateMesh, Line2D, MeshInstance3D, StandardMaterial3D,
+    },
+    prelude::*,
+};
+
+/// The width of the 2D line, in pixels.
+const DEBUG_LINE_WIDTH: f32 = 2.0;
+
+/// An editor-only line from the follower to the target, drawn by a child of the animator.
+/// The child has no owner, so it's never saved with the scene.
+pub struct DebugLine {
+    mesh: Option<Gd<MeshInstance3D>>,
+    line: Option<Gd<Line2D>>,
+}
+
+impl DebugLine {
+    pub fn new() -> Self {
+        Self {
+            mesh: None,
+            line: None,
+        }
+    }
+
+    /// Draws the line between the global positions of the nodes, in 3D or in 2D
+    /// depending on the nodes. Nodes without a position are not drawn.
+    pub fn draw(&mut self, owner: &Gd<Node>, from: Gd<Node>, to: Gd<Node>) {
+        if let (Ok(from), Ok(to)) = (
+            from.clone().try_cast::<Node3D>(),
+            to.clone().try_cast::<Node3D>(),
+        ) {
+            self.draw_3d(owner, from.get_global_position(), to.get_global_position());
+            return;
+        }
+
+        if let (Some(from), Some(to)) = (canvas_position(from), canvas_position(to)) {
+            self.draw_2d(owner, from, to);
+            return;
+        }
+
+        self.clear();
+    }
+
+    pub fn clear(&mut self) {
+        if let Some(mut mesh) = self.mesh.take().filter(|mesh| mesh.is_instance_valid()) {
+            mesh.queue_free();
+        }
+        if let Some(mut line) = self.line.take().filter(|line| line.is_instance_valid()) {
+            line.queue_free();
+        }
+    }
+
+    fn draw_3d(&mut self, owner: &Gd<Node>, from: Vector3, to: Vector3) {
+        // The child may have been freed along with the scene it was added to.
+        let instance = match self.mesh.take().filter(|mesh| mesh.is_instance_valid()) {
+            Some(instance) => instance,
+            None => {
+                let mut material = StandardMaterial3D::new_gd();
+                material.set_shading_mode(base_material_3d::ShadingMode::UNSHADED);
+                material.set_albedo(Color::ORANGE);
+
+                let mut instance = MeshInstance3D::new_alloc();
+                instance.set_mesh(ImmediateMesh::new_gd().upcast());
+                instance.set_material_override(material.upcast());
+                add_child_deferred(owner, instance.clone().upcast());
+                instance
+            }
+        };
+
+        // A Node3D under a plain Node is placed in the global space, so the points need no transform.
+        if let Some(mesh) = instance.get_mesh() {
+            let mut mesh = mesh.cast::<ImmediateMesh>();
+            mesh.clear_surfaces();
+            mesh.surface_begin(mesh::PrimitiveType::LINES);
+            mesh.surface_add_vertex(from);
+            mesh.surface_add_vertex(to);
+            mesh.surface_end();
+        }
+
+        self.mesh = Some(instance);
+    }
+
+    fn draw_2d(&mut self, owner: &Gd<Node>, from: Vector2, to: Vector2) {
+        let mut line = match self.line.take().filter(|line| line.is_instance_valid()) {
+            Some(line) => line,
+            None => {
+                let mut line = Line2D::new_alloc();
+                line.set_width(DEBUG_LINE_WIDTH);
+                line.set_default_color(Color::ORANGE);
+                add_child_deferred(owner, line.clone().upcast());
+                line
+            }
+        };
+
+        let mut points = PackedVector2Array::new();
+        points.push(from);
+        points.push(to);
+        line.set_points(points);
+
+        self.line = Some(line);
+    }
+}
+
+/// The child is added deferred, as adding it notifies the animator, which is borrowed while drawing.
+fn add_child_deferred(owner: &Gd<Node>, child: Gd<Node>) {
+    owner
+        .clone()
+        .call_deferred("add_child".into(), &[child.to_variant()]);
+}
+
+fn canvas_position(node: Gd<Node>) -> Option<Vector2> {
+    if let Ok(node) = node.clone().try_cast::<Node2D>() {
+        return Some(node.get_global_position());
+    }
+    if let Ok(node) = node.try_cast::<Control>() {
+        return Some(node.get_global_position());
+    }
+
+    None
+}
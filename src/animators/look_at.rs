@@ -0,0 +1,221 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine},
+    prelude::*,
+};
+
+use super::{warn_on_period, warn_on_recovery, AnimatorError, InterpolationMode};
+use crate::second_order_systems::*;
+
+/// Directions closer to each other than this are treated as parallel.
+const PARALLEL_EPSILON: f32 = 1e-6;
+
+/// Smoothly rotates the follower to face the target, e.g. for cameras and turrets.
+/// The follower looks along its -Z axis, as Node3D.look_at does.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorLookAt3D {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node3D>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node3D>>,
+    #[export]
+    up: Vector3,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+    // The fastest the follower may turn, in radians per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_angular_speed)]
+    max_angular_speed: f32,
+
+    system: SecondOrderSystemQuaternion,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorLookAt3D {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node3D>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node3D>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_angular_speed(&mut self, value: f32) {
+        self.max_angular_speed = value.max(0.0);
+        self.system.update_max_speed(self.max_angular_speed);
+    }
+
+    /// The global rotation that makes the follower face the target.
+    fn _get_look_rotation(&self) -> Quaternion {
+        let (origin, rotation, _) =
+            decompose_transform_3d(self.follower.as_ref().unwrap().get_global_transform());
+        let target = self.target.as_ref().unwrap().get_global_position();
+
+        let direction = target - origin;
+        if direction.length_squared() < PARALLEL_EPSILON {
+            return rotation;
+        }
+
+        // Looking straight along up has no defined roll, so the current back axis of the follower
+        // is used as up instead. It is what up turns into when the follower pitches toward the target.
+        let parallel = direction
+            .normalized()
+            .cross(self.up.normalized())
+            .length_squared()
+            < PARALLEL_EPSILON;
+        let up = if parallel {
+            Basis::from_quat(rotation) * Vector3::BACK
+        } else {
+            self.up
+        };
+
+        Transform3D::new(Basis::IDENTITY, origin)
+            .looking_at(target, up)
+            .basis
+            .to_quat()
+    }
+
+    fn _update_initial_values(&mut self) {
+        let (_, current, _) =
+            decompose_transform_3d(self.follower.as_ref().unwrap().get_global_transform());
+        let previous = self._get_look_rotation();
+
+        self.system.update_initial_values(
+            previous,
+            current,
+            SecondOrderSystemQuaternion::zero_derivative(),
+        );
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let input = self._get_look_rotation();
+        let rotation = self.system.update(input, delta);
+        warn_on_recovery(self.system.take_recovered());
+
+        let follower = self.follower.as_mut().unwrap();
+        let (origin, _, scale) = decompose_transform_3d(follower.get_global_transform());
+        follower.set_global_transform(compose_transform_3d(origin, rotation, scale));
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        if self.target.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        }
+        if self.follower.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorLookAt3D, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        up: Vector3::UP,
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        max_angular_speed: 0.0,
+        system: SecondOrderSystemQuaternion::new(period, damping, response),
+        base,
+    }
+});
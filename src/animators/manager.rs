@@ -0,0 +1,339 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine},
+    prelude::*,
+};
+
+use super::{warn_on_recovery, InterpolationMode, WAKE_THRESHOLD_FACTOR};
+use crate::second_order_systems::*;
+
+/// A follower smoothed toward a target by the manager, both in global space.
+enum ManagedEntry {
+    Node3D {
+        follower: Gd<Node3D>,
+        target: Gd<Node3D>,
+        system: SecondOrderSystemVector3,
+        // The target position the entry fell asleep at, while it's asleep.
+        sleep_input: Option<Vector3>,
+    },
+    Node2D {
+        follower: Gd<Node2D>,
+        target: Gd<Node2D>,
+        system: SecondOrderSystemVector2,
+        sleep_input: Option<Vector2>,
+    },
+}
+
+impl ManagedEntry {
+    fn new(
+        follower: Gd<Node>,
+        target: Gd<Node>,
+        period: f32,
+        damping: f32,
+        response: f32,
+    ) -> Option<Self> {
+        if let (Ok(follower), Ok(target)) = (
+            follower.clone().try_cast::<Node3D>(),
+            target.clone().try_cast::<Node3D>(),
+        ) {
+            let mut system = SecondOrderSystemVector3::new(period, damping, response);
+            system.update_initial_values(
+                target.get_global_position(),
+                follower.get_global_position(),
+                SecondOrderSystemVector3::zero_derivative(),
+            );
+
+            return Some(ManagedEntry::Node3D {
+                follower,
+                target,
+                system,
+                sleep_input: None,
+            });
+        }
+
+        if let (Ok(follower), Ok(target)) =
+            (follower.try_cast::<Node2D>(), target.try_cast::<Node2D>())
+        {
+            let mut system = SecondOrderSystemVector2::new(period, damping, response);
+            system.update_initial_values(
+                target.get_global_position(),
+                follower.get_global_position(),
+                SecondOrderSystemVector2::zero_derivative(),
+            );
+
+            return Some(ManagedEntry::Node2D {
+                follower,
+                target,
+                system,
+                sleep_input: None,
+            });
+        }
+
+        None
+    }
+
+    fn follower_id(&self) -> InstanceId {
+        match self {
+            ManagedEntry::Node3D { follower, .. } => follower.instance_id(),
+            ManagedEntry::Node2D { follower, .. } => follower.instance_id(),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match self {
+            ManagedEntry::Node3D {
+                follower, target, ..
+            } => follower.is_instance_valid() && target.is_instance_valid(),
+            ManagedEntry::Node2D {
+                follower, target, ..
+            } => follower.is_instance_valid() && target.is_instance_valid(),
+        }
+    }
+
+    fn is_settled(&self, threshold: f32) -> bool {
+        match self {
+            ManagedEntry::Node3D { system, .. } => system.is_settled(threshold, threshold),
+            ManagedEntry::Node2D { system, .. } => system.is_settled(threshold, threshold),
+        }
+    }
+
+    fn velocity(&self) -> Variant {
+        match self {
+            ManagedEntry::Node3D { system, .. } => system.velocity().to_variant(),
+            ManagedEntry::Node2D { system, .. } => system.velocity().to_variant(),
+        }
+    }
+
+    /// Steps the entry. The sleep threshold is given when the entries fall asleep once settled.
+    fn update(&mut self, delta: f64, sleep_threshold: Option<f32>) {
+        match self {
+            ManagedEntry::Node3D {
+                follower,
+                target,
+                system,
+                sleep_input,
+            } => {
+                let input = target.get_global_position();
+                if step_entry(system, sleep_input, input, delta, sleep_threshold) {
+                    follower.set_global_position(system.current());
+                }
+            }
+            ManagedEntry::Node2D {
+                follower,
+                target,
+                system,
+                sleep_input,
+            } => {
+                let input = target.get_global_position();
+                if step_entry(system, sleep_input, input, delta, sleep_threshold) {
+                    follower.set_global_position(system.current());
+                }
+            }
+        }
+    }
+}
+
+/// Steps the system of an entry, unless it's asleep and the target hasn't moved far enough
+/// to wake it up. Returns whether the system was stepped.
+fn step_entry<T, S>(
+    system: &mut S,
+    sleep_input: &mut Option<T>,
+    input: T,
+    delta: f64,
+    sleep_threshold: Option<f32>,
+) -> bool
+where
+    T: SystemMetric + Copy,
+    S: SecondOrderSystem<T>,
+{
+    if let (Some(previous), Some(threshold)) = (*sleep_input, sleep_threshold) {
+        if input.distance(previous) <= threshold * WAKE_THRESHOLD_FACTOR {
+            return false;
+        }
+    }
+
+    *sleep_input = None;
+    system.update(input, delta);
+    warn_on_recovery(system.take_recovered());
+
+    if let Some(threshold) = sleep_threshold {
+        if system.is_settled(threshold, threshold) {
+            *sleep_input = Some(input);
+        }
+    }
+
+    true
+}
+
+/// Smooths the global positions of many followers in a single node, so large scenes
+/// don't pay the per-node processing cost of an animator for every object.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorManager {
+    #[export]
+    active: bool,
+    #[export]
+    run_in_editor: bool,
+    #[export]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+
+    #[export]
+    settle_threshold: f32,
+    // Settled entries are not stepped until their target moves.
+    #[export]
+    auto_sleep: bool,
+    #[export]
+    sleep_threshold: f32,
+
+    entries: Vec<ManagedEntry>,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorManager {
+    /// Starts smoothing the follower toward the target. Both must be either Node3D or Node2D.
+    #[func]
+    fn register(
+        &mut self,
+        follower: Gd<Node>,
+        target: Gd<Node>,
+        period: f32,
+        damping: f32,
+        response: f32,
+    ) -> bool {
+        if !(period.is_finite() && period > 0.0)
+            || !(damping.is_finite() && damping >= 0.0)
+            || !response.is_finite()
+        {
+            godot_warn!(
+                "Animator manager error: The period must be positive, the damping must not be negative and the response must be finite."
+            );
+            return false;
+        }
+
+        let Some(entry) = ManagedEntry::new(follower, target, period, damping, response) else {
+            godot_warn!(
+                "Animator manager error: The follower and the target must be both Node3D or both Node2D."
+            );
+            return false;
+        };
+
+        self._unregister_by_id(entry.follower_id());
+        self.entries.push(entry);
+        true
+    }
+
+    #[func]
+    fn unregister(&mut self, follower: Gd<Node>) {
+        self._unregister_by_id(follower.instance_id());
+    }
+
+    #[func]
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    #[func]
+    fn get_entry_count(&self) -> i64 {
+        self.entries.len() as i64
+    }
+
+    /// Whether the follower has reached its target and almost stopped, within settle_threshold.
+    /// A follower that is not registered is never settled.
+    #[func]
+    fn is_settled(&self, follower: Gd<Node>) -> bool {
+        self._find_by_id(follower.instance_id())
+            .is_some_and(|entry| entry.is_settled(self.settle_threshold))
+    }
+
+    /// The velocity of the follower per second, or null if it's not registered.
+    #[func]
+    fn get_velocity(&self, follower: Gd<Node>) -> Variant {
+        self._find_by_id(follower.instance_id())
+            .map_or(Variant::nil(), ManagedEntry::velocity)
+    }
+
+    fn _find_by_id(&self, id: InstanceId) -> Option<&ManagedEntry> {
+        self.entries.iter().find(|entry| entry.follower_id() == id)
+    }
+
+    fn _unregister_by_id(&mut self, id: InstanceId) {
+        self.entries.retain(|entry| entry.follower_id() != id);
+    }
+
+    fn _is_running(&self) -> bool {
+        self.active && (self.run_in_editor || !self.is_editor)
+    }
+
+    fn _update(&mut self, delta: f64) {
+        // Freed followers or targets are dropped instead of being dereferenced.
+        self.entries.retain(ManagedEntry::is_valid);
+
+        let sleep_threshold = self.auto_sleep.then_some(self.sleep_threshold);
+        for entry in self.entries.iter_mut() {
+            entry.update(delta, sleep_threshold);
+        }
+    }
+
+    fn _proceed_notification(&mut self, notification: NodeNotification) {
+        if let NodeNotification::EnterTree | NodeNotification::Ready = notification {
+            self.is_editor = Engine::singleton().is_editor_hint();
+        }
+
+        match (notification, self.interpolation_mode) {
+            (NodeNotification::Process, InterpolationMode::Process) => {
+                if self._is_running() {
+                    let delta = self.base().get_process_delta_time();
+                    self._update(delta);
+                }
+            }
+            (NodeNotification::PhysicsProcess, InterpolationMode::Physics) => {
+                if self._is_running() {
+                    let delta = self.base().get_physics_process_delta_time();
+                    self._update(delta);
+                }
+            }
+            (NodeNotification::Ready, _) => {
+                self.base_mut().set_process(true);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[godot_api]
+impl INode for AnimatorManager {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            active: true,
+            run_in_editor: false,
+            interpolation_mode: InterpolationMode::Physics,
+            is_editor: Engine::singleton().is_editor_hint(),
+            settle_threshold: 0.001,
+            auto_sleep: false,
+            sleep_threshold: 0.001,
+            entries: Vec::new(),
+            base,
+        }
+    }
+
+    // See the generate_animator macro on why both the process methods and on_notification are used.
+
+    fn process(&mut self, delta: f64) {
+        if self.interpolation_mode == InterpolationMode::Process && self._is_running() {
+            self._update(delta);
+        }
+    }
+
+    fn physics_process(&mut self, delta: f64) {
+        if self.interpolation_mode == InterpolationMode::Physics && self._is_running() {
+            self._update(delta);
+        }
+    }
+
+    fn on_notification(&mut self, notification: NodeNotification) {
+        self._proceed_notification(notification);
+    }
+}
@@ -1,51 +1,650 @@
 use godot::{
-    engine::{notify::NodeNotification, Engine},
+    engine::{
+        light_3d, notify::NodeNotification, AudioStreamPlayer, AudioStreamPlayer2D,
+        AudioStreamPlayer3D, Camera2D, Camera3D, CanvasItem, CanvasModulate, Control,
+        DirectionalLight3D, Engine, GpuParticles2D, GpuParticles3D, Light3D,
+        PhysicsRayQueryParameters3D, Range, SpringArm3D,
+    },
     prelude::*,
 };
 
-use crate::second_order_systems::*;
+use crate::{
+    parameters::{AnimatorDefaults, SecondOrderParameters, DEFAULTS_GROUP},
+    second_order_systems::*,
+};
+use debug::DebugLine;
+
+#[macro_use]
+mod callbacks;
+
+mod blend_shape;
+mod debug;
+mod look_at;
+mod manager;
+mod property;
+mod shader;
+mod transform;
 
 #[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
 #[godot(via = GString)]
 pub enum InterpolationMode {
     Process,
     Physics,
+    /// The animator is only updated by calling advance from a script.
+    Manual,
+}
+
+/// Curated period, damping and response combinations. Custom keeps the values set by hand.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
+#[godot(via = GString)]
+pub enum InterpolationPreset {
+    Custom,
+    Smooth,
+    Snappy,
+    Bouncy,
+    Elastic,
+    Sluggish,
+}
+
+impl InterpolationPreset {
+    /// The period, damping and response of the preset, None for Custom.
+    fn parameters(self) -> Option<(f32, f32, f32)> {
+        match self {
+            InterpolationPreset::Custom => None,
+            InterpolationPreset::Smooth => Some((1.0, 1.0, 0.0)),
+            InterpolationPreset::Snappy => Some((4.0, 0.8, 1.0)),
+            InterpolationPreset::Bouncy => Some((2.0, 0.3, 1.0)),
+            InterpolationPreset::Elastic => Some((1.5, 0.15, 2.0)),
+            InterpolationPreset::Sluggish => Some((0.5, 1.0, 0.0)),
+        }
+    }
+}
+
+/// What happens to the animation when the animator is activated.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
+#[godot(via = GString)]
+pub enum ActivationBehavior {
+    /// Starts over from the current follower and target values.
+    SnapReference,
+    /// Continues from the state the animation was stopped in, so toggling active mid-flight doesn't jump.
+    Resume,
+}
+
+/// Where the animator takes the value the follower is smoothed toward.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
+#[godot(via = GString)]
+pub enum TargetSource {
+    Node,
+    Value,
+    // Only supported by the position animators.
+    Mouse,
+}
+
+/// How the output of the animator is written to the follower.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
+#[godot(via = GString)]
+pub enum AnimationMode {
+    // The follower is set to the output.
+    Absolute,
+    // The target is an offset, e.g. a recoil kick, which is smoothed and put on top of
+    // what other drivers have set to the follower before the animator processes.
+    Additive,
 }
 
 #[derive(Debug)]
 enum AnimatorError {
     NodeNotSpecified(&'static str),
+    ResourceNotSpecified(&'static str),
+    PropertyNotSpecified(&'static str),
+    PropertyNotFound(&'static str, String),
+    NotAnAnimator(String),
+    InvalidState,
+    PathNotResolved(&'static str, String),
+    GroupNotResolved(&'static str, String),
+    BlendShapeNotFound(&'static str, String),
+    UnsupportedPropertyType(&'static str, String, VariantType),
+    PropertyTypesDiffer(String, String),
+    MouseNotSupported,
+    MouseNeedsPhysics,
 }
 
 impl std::fmt::Display for AnimatorError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
+        match self {
             AnimatorError::NodeNotSpecified(node) => {
                 write!(f, "The {} node is not specified.", node)
             }
+            AnimatorError::ResourceNotSpecified(resource) => {
+                write!(f, "The {} resource is not specified.", resource)
+            }
+            AnimatorError::PropertyNotSpecified(property) => {
+                write!(f, "The {} property is not specified.", property)
+            }
+            AnimatorError::PropertyNotFound(node, property) => {
+                write!(
+                    f,
+                    "The {} node has no property named \"{}\" of the animated type.",
+                    node, property
+                )
+            }
+            AnimatorError::NotAnAnimator(node) => {
+                write!(f, "The {} node is not an animator.", node)
+            }
+            AnimatorError::PathNotResolved(node, path) => {
+                write!(
+                    f,
+                    "The {} path \"{}\" doesn't point to a node of the right type.",
+                    node, path
+                )
+            }
+            AnimatorError::GroupNotResolved(node, group) => {
+                write!(
+                    f,
+                    "The {} group \"{}\" has no node of the right type.",
+                    node, group
+                )
+            }
+            AnimatorError::BlendShapeNotFound(node, blend_shape) => {
+                write!(
+                    f,
+                    "The {} mesh has no blend shape named \"{}\".",
+                    node, blend_shape
+                )
+            }
+            AnimatorError::UnsupportedPropertyType(node, property, variant_type) => {
+                write!(
+                    f,
+                    "The {} property \"{}\" is of the type {:?}, only float, Vector2, Vector3, Color and Quaternion properties can be animated.",
+                    node, property, variant_type
+                )
+            }
+            AnimatorError::PropertyTypesDiffer(follower_property, target_property) => {
+                write!(
+                    f,
+                    "The follower property \"{}\" and the target property \"{}\" are of different types.",
+                    follower_property, target_property
+                )
+            }
+            AnimatorError::MouseNotSupported => {
+                write!(f, "This animator can't follow the mouse.")
+            }
+            AnimatorError::MouseNeedsPhysics => {
+                write!(
+                    f,
+                    "This animator follows the mouse with a physics ray, which needs the Physics interpolation mode."
+                )
+            }
+            AnimatorError::InvalidState => {
+                write!(
+                    f,
+                    "The saved state is missing values or has values of wrong types."
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for AnimatorError {}
 
+/// Operations the animators need on the values they animate.
+trait AnimatedValue: Copy {
+    /// The offset that leaves a value unchanged.
+    fn no_offset() -> Self;
+
+    /// Displaces the value by the offset the follower keeps from the target.
+    fn offset_by(self, offset: Self) -> Self;
+
+    /// Undoes offset_by, returning the value the offset has been put on.
+    fn remove_offset(self, offset: Self) -> Self;
+}
+
+macro_rules! impl_animated_value_for_additive_types {
+    ($($type:ty: $zero:expr),*) => {
+        $(
+            impl AnimatedValue for $type {
+                fn no_offset() -> Self {
+                    $zero
+                }
+
+                fn offset_by(self, offset: Self) -> Self {
+                    self + offset
+                }
+
+                fn remove_offset(self, offset: Self) -> Self {
+                    self - offset
+                }
+            }
+        )*
+    };
+}
+
+impl_animated_value_for_additive_types!(f32: 0.0, Vector2: Vector2::ZERO, Vector3: Vector3::ZERO);
+
+impl AnimatedValue for Quaternion {
+    fn no_offset() -> Self {
+        Quaternion::default()
+    }
+
+    /// The offset is an additional rotation in the local space of the target.
+    fn offset_by(self, offset: Self) -> Self {
+        self * offset
+    }
+
+    fn remove_offset(self, offset: Self) -> Self {
+        self * offset.inverse()
+    }
+}
+
+impl AnimatedValue for Color {
+    fn no_offset() -> Self {
+        Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn offset_by(self, offset: Self) -> Self {
+        Color::from_rgba(
+            self.r + offset.r,
+            self.g + offset.g,
+            self.b + offset.b,
+            self.a + offset.a,
+        )
+    }
+
+    fn remove_offset(self, offset: Self) -> Self {
+        Color::from_rgba(
+            self.r - offset.r,
+            self.g - offset.g,
+            self.b - offset.b,
+            self.a - offset.a,
+        )
+    }
+}
+
+/// Stores the system state in a Variant exactly, so a saved animation continues seamlessly.
+trait StateValue: Sized {
+    fn to_state(self) -> Variant;
+
+    fn from_state(state: &Variant) -> Option<Self>;
+}
+
+macro_rules! impl_state_value_for_variant_types {
+    ($($type:ty),*) => {
+        $(
+            impl StateValue for $type {
+                fn to_state(self) -> Variant {
+                    self.to_variant()
+                }
+
+                fn from_state(state: &Variant) -> Option<Self> {
+                    state.try_to::<$type>().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_state_value_for_variant_types!(f32, Vector2, Vector3, Color);
+
+impl StateValue for Quaternion {
+    /// The components are stored explicitly, as the derivative is not a unit quaternion.
+    fn to_state(self) -> Variant {
+        PackedFloat32Array::from(&[self.x, self.y, self.z, self.w][..]).to_variant()
+    }
+
+    fn from_state(state: &Variant) -> Option<Self> {
+        let components = state.try_to::<PackedFloat32Array>().ok()?;
+        match components.as_slice() {
+            &[x, y, z, w] => Some(Quaternion::new(x, y, z, w)),
+            _ => None,
+        }
+    }
+}
+
+/// Measures how far a value went past the target.
+trait Overshoot: Copy {
+    /// The distance past the target along the direction from the start to the target.
+    /// It is negative while the value hasn't reached the target yet.
+    fn overshoot(self, target: Self, start: Self) -> f32;
+}
+
+impl Overshoot for f32 {
+    fn overshoot(self, target: Self, start: Self) -> f32 {
+        (self - target) * (target - start).signum()
+    }
+}
+
+macro_rules! impl_overshoot_for_vector_types {
+    ($($type:ty),*) => {
+        $(
+            impl Overshoot for $type {
+                fn overshoot(self, target: Self, start: Self) -> f32 {
+                    let direction = target - start;
+                    if direction.length_squared() <= 0.0 {
+                        return 0.0;
+                    }
+
+                    (self - target).dot(direction.normalized())
+                }
+            }
+        )*
+    };
+}
+
+impl_overshoot_for_vector_types!(Vector2, Vector3);
+
+impl Overshoot for Color {
+    fn overshoot(self, target: Self, start: Self) -> f32 {
+        let to_vector4 = |color: Color| Vector4::new(color.r, color.g, color.b, color.a);
+        let direction = to_vector4(target) - to_vector4(start);
+        if direction.length_squared() <= 0.0 {
+            return 0.0;
+        }
+
+        (to_vector4(self) - to_vector4(target)).dot(direction.normalized())
+    }
+}
+
+impl Overshoot for Quaternion {
+    /// The angle past the target around the axis of the rotation from the start to the target.
+    fn overshoot(self, target: Self, start: Self) -> f32 {
+        let vector_part = |rotation: Quaternion| {
+            let rotation = if rotation.w < 0.0 {
+                -rotation
+            } else {
+                rotation
+            };
+            let log = rotation.normalized().log();
+            Vector3::new(log.x, log.y, log.z)
+        };
+
+        let direction = vector_part(target * start.inverse());
+        if direction.length_squared() <= 0.0 {
+            return 0.0;
+        }
+
+        2.0 * vector_part(self * target.inverse()).dot(direction.normalized())
+    }
+}
+
+/// Lets some axes of a vector follow the target directly.
+trait AxisMask {
+    /// Takes the smoothed component where the mask is non-zero and the target component otherwise.
+    fn apply_axis_mask(smoothed: Self, target: Self, mask: Self) -> Self;
+}
+
+#[inline]
+fn mask_component(smoothed: f32, target: f32, mask: f32) -> f32 {
+    if mask != 0.0 {
+        smoothed
+    } else {
+        target
+    }
+}
+
+impl AxisMask for Vector2 {
+    fn apply_axis_mask(smoothed: Self, target: Self, mask: Self) -> Self {
+        Vector2::new(
+            mask_component(smoothed.x, target.x, mask.x),
+            mask_component(smoothed.y, target.y, mask.y),
+        )
+    }
+}
+
+impl AxisMask for Vector3 {
+    fn apply_axis_mask(smoothed: Self, target: Self, mask: Self) -> Self {
+        Vector3::new(
+            mask_component(smoothed.x, target.x, mask.x),
+            mask_component(smoothed.y, target.y, mask.y),
+            mask_component(smoothed.z, target.z, mask.z),
+        )
+    }
+}
+
+/// Keeps a value within a range, component-wise for vectors.
+trait ClampComponents: Sized {
+    fn clamp_components(self, min: Self, max: Self) -> Self;
+
+    /// Clamps the value and drops the velocity components pushing it further out of the range,
+    /// so the follower doesn't build up speed against the bound.
+    fn clamp_with_velocity(self, velocity: Self, min: Self, max: Self) -> (Self, Self);
+}
+
+#[inline]
+fn clamp_component(value: f32, velocity: f32, min: f32, max: f32) -> (f32, f32) {
+    if value <= min {
+        (min, velocity.max(0.0))
+    } else if value >= max {
+        (max, velocity.min(0.0))
+    } else {
+        (value, velocity)
+    }
+}
+
+impl ClampComponents for f32 {
+    fn clamp_components(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    fn clamp_with_velocity(self, velocity: Self, min: Self, max: Self) -> (Self, Self) {
+        clamp_component(self, velocity, min, max)
+    }
+}
+
+impl ClampComponents for Vector2 {
+    fn clamp_components(self, min: Self, max: Self) -> Self {
+        Vector2::new(
+            self.x.clamp_components(min.x, max.x),
+            self.y.clamp_components(min.y, max.y),
+        )
+    }
+
+    fn clamp_with_velocity(self, velocity: Self, min: Self, max: Self) -> (Self, Self) {
+        let (x, vx) = clamp_component(self.x, velocity.x, min.x, max.x);
+        let (y, vy) = clamp_component(self.y, velocity.y, min.y, max.y);
+        (Vector2::new(x, y), Vector2::new(vx, vy))
+    }
+}
+
+impl ClampComponents for Vector3 {
+    fn clamp_components(self, min: Self, max: Self) -> Self {
+        Vector3::new(
+            self.x.clamp_components(min.x, max.x),
+            self.y.clamp_components(min.y, max.y),
+            self.z.clamp_components(min.z, max.z),
+        )
+    }
+
+    fn clamp_with_velocity(self, velocity: Self, min: Self, max: Self) -> (Self, Self) {
+        let (x, vx) = clamp_component(self.x, velocity.x, min.x, max.x);
+        let (y, vy) = clamp_component(self.y, velocity.y, min.y, max.y);
+        let (z, vz) = clamp_component(self.z, velocity.z, min.z, max.z);
+        (Vector3::new(x, y, z), Vector3::new(vx, vy, vz))
+    }
+}
+
+/// The step the animators are warmed up with on Ready.
+const WARMUP_STEP: f64 = 1.0 / 120.0;
+
+/// The smallest change of the target that starts a new overshoot measurement.
+const OVERSHOOT_TARGET_EPSILON: f32 = 1e-4;
+
+/// The range of the `fov` property of Camera3D, as it is exposed in the inspector.
+const CAMERA_3D_FOV_RANGE: (f32, f32) = (1.0, 179.0);
+
+/// The largest number of steps a single frame is split into, so a long hitch can't stall the game.
+const MAX_SPLIT_STEPS: u32 = 64;
+
+/// Changing the size of a Control triggers layout, so smaller changes are not applied.
+const CONTROL_SIZE_EPSILON: f32 = 1e-3;
+
+/// The smallest `pitch_scale` the audio players accept, as it is exposed in the inspector.
+const MIN_PITCH_SCALE: f32 = 0.01;
+
+/// How many sleep thresholds the target has to move to wake a sleeping animator up. The gap keeps
+/// a target jittering around the threshold from waking it and putting it to sleep every frame.
+const WAKE_THRESHOLD_FACTOR: f32 = 2.0;
+
+/// Warns when the systems can't use the period as is, as they don't depend on the engine themselves.
+fn warn_on_period(period: f32) {
+    if checked_period(period).1 {
+        godot_warn!(
+            "Animator warning: The period {} is not positive, the follower snaps to the target instead.",
+            period
+        );
+    }
+}
+
+/// Warns when a system has reset a state that wasn't finite, as the systems don't depend on the engine themselves.
+fn warn_on_recovery(recovered: bool) {
+    if recovered {
+        godot_warn!("Animator warning: The state is not finite, it's reset to the target.");
+    }
+}
+
+/// Switches the color systems to the color space of the animator, once it's changed.
+fn sync_color_space(
+    system: &mut SecondOrderSystemColor,
+    follower_systems: &mut [SecondOrderSystemColor],
+    space: ColorSpace,
+) {
+    for system in std::iter::once(system).chain(follower_systems.iter_mut()) {
+        if system.space() != space {
+            system.update_space(space);
+        }
+    }
+}
+
+#[inline]
+fn clamp_color(color: Color, min: f32, max: f32) -> Color {
+    Color::from_rgba(
+        color.r.clamp(min, max),
+        color.g.clamp(min, max),
+        color.b.clamp(min, max),
+        color.a.clamp(min, max),
+    )
+}
+
+/// The point under the mouse in global space: where the ray from the current camera hits
+/// a physics body, or the point of the ray closest to the node when nothing is hit.
+/// The physics space is only safe to query during the physics process.
+fn mouse_position_3d(node: &Gd<Node3D>) -> Option<Vector3> {
+    let viewport = node.get_viewport()?;
+    let camera = viewport.get_camera_3d()?;
+    let mouse = viewport.get_mouse_position();
+
+    let from = camera.project_ray_origin(mouse);
+    let normal = camera.project_ray_normal(mouse);
+    let to = from + normal * camera.get_far();
+
+    let hit = node
+        .get_world_3d()
+        .and_then(|world| world.get_direct_space_state())
+        .and_then(|mut space| {
+            let query = PhysicsRayQueryParameters3D::create(from, to)?;
+            space
+                .intersect_ray(query)
+                .get("position")?
+                .try_to::<Vector3>()
+                .ok()
+        });
+
+    hit.or_else(|| {
+        let depth = (node.get_global_position() - from).dot(normal);
+        Some(from + normal * depth)
+    })
+}
+
 macro_rules! generate_animator {
     // This macro generates animator classes for different node properties and types.
     // Parameters:
     // $node_name: The name of the generated animator class.
     // $node_type: The type of the target node (e.g., Node3D, Node2D).
     // $system_type: The type of the second-order system used for interpolation.
+    // $system_inner_type: The type of the values the system works with (e.g., Vector3).
     // $system_inner_type_default: The default value for the system's inner type (e.g., Vector3::ZERO).
+    // The initial derivative is taken from the system instead, as it may differ (e.g., for Quaternion).
     // $get_node_value: A closure to get the current value from the target node.
     // $set_node_value: A closure to set the new value to the target node.
-    ($node_name:ident, $node_type:ty, $system_type:ty, $system_inner_type_default:expr, $get_node_value:expr, $set_node_value:expr) => {
+    // global (optional): The name of a flag field and an alternate getter/setter pair,
+    // which are used instead of the default ones when the flag is set.
+    // axis_mask (optional): The type and the default of a per-axis mask for vector animators.
+    // Axes with a zero mask component follow the target directly instead of being smoothed.
+    // clamp (optional): The type and the default range of the clamp_min and clamp_max exports,
+    // which bound the output when enable_clamp is set.
+    // anisotropic (optional): The vector type of the per-axis parameters, which are used instead
+    // of the shared ones when anisotropic is set.
+    // speed_limit (optional): The names of a max speed export and its setter, capping how fast
+    // the follower moves. Zero means no limit.
+    // velocity (optional): The type of the velocity source and its property holding the velocity
+    // of the target, e.g. linear_velocity of a RigidBody3D. It's used instead of estimating it.
+    // mouse (optional): A closure receiving the animator and the follower, returning the position
+    // of the mouse in the space of the follower value. Enables the Mouse target source.
+    // mouse_in_physics (optional): Whether the mouse closure queries the physics space, so the
+    // Mouse target source is only valid in the Physics interpolation mode.
+    // fields (optional): Additional animator-specific fields with their default values.
+    // pre_process (optional): A closure receiving the animator and a value read from a node,
+    // returning the value that is actually fed to the system.
+    // post_process (optional): A closure receiving the animator, the input and the system output,
+    // returning the value that is actually set to the follower.
+    (
+        $node_name:ident,
+        $node_type:ty,
+        $system_type:ty,
+        $system_inner_type:ty,
+        $system_inner_type_default:expr,
+        $get_node_value:expr,
+        $set_node_value:expr
+        $(, global { $global_flag:ident, $get_global_node_value:expr, $set_global_node_value:expr $(,)? } )?
+        $(, axis_mask: $axis_mask_type:ty = $axis_mask_default:expr )?
+        $(, clamp: $clamp_type:ty = $clamp_range:expr )?
+        $(, anisotropic: $anisotropic_type:ty )?
+        $(, speed_limit { $speed_limit:ident, $set_speed_limit:ident $(,)? } )?
+        $(, velocity: $velocity_source_type:ty = $velocity_property:literal )?
+        $(, mouse = $get_mouse_value:expr )?
+        $(, mouse_in_physics: $mouse_in_physics:literal )?
+        $(, fields { $( $(#[$field_attr:meta])* $field:ident: $field_type:ty = $field_default:expr ),* $(,)? } )?
+        $(, pre_process = $pre_process:expr )?
+        $(, post_process = $post_process:expr )?
+    ) => {
         #[derive(GodotClass)]
         #[class(tool, base=Node)]
         struct $node_name {
             #[export]
+            #[var(get, set = set_follower)]
             follower: Option<Gd<$node_type>>,
             #[export]
+            #[var(get, set = set_followers)]
+            followers: Array<Gd<$node_type>>,
+            #[export]
+            #[var(get, set = set_target)]
             target: Option<Gd<$node_type>>,
+            // The paths take precedence over the references, which break on instanced scenes.
+            #[export]
+            #[var(get, set = set_follower_path)]
+            follower_path: NodePath,
+            #[export]
+            #[var(get, set = set_target_path)]
+            target_path: NodePath,
+            // The first node of the group becomes the target, unless it's already set.
+            #[export]
+            #[var(get, set = set_target_group)]
+            target_group: StringName,
+            #[export]
+            #[var(get, set = set_target_source)]
+            target_source: TargetSource,
+            #[export]
+            target_value: $system_inner_type,
+            #[export]
+            offset: $system_inner_type,
+            // Other drivers of the property should write it before the animator processes,
+            // e.g. from an earlier node in the tree. Writes after it replace the offset for the frame.
+            #[export]
+            #[var(get, set = set_mode)]
+            mode: AnimationMode,
+            // The velocity the follower starts with on activation, e.g. to flick it. It's in the
+            // units of get_velocity, so rotations take the angular velocity in radians per second
+            // and colors in the Hsv color space take h, s, v per second in r, g, b.
+            #[export]
+            initial_velocity: <$system_inner_type as SystemVelocity>::Velocity,
 
             #[export]
             #[var(get, set = set_active)]
@@ -56,85 +655,1418 @@ macro_rules! generate_animator {
             #[export]
             #[var(get, set = set_interpolation_mode)]
             interpolation_mode: InterpolationMode,
+            // In the Physics mode, the followers are moved along their velocity every frame
+            // between the physics steps, so a low physics rate doesn't look choppy.
+            #[export]
+            render_interpolated: bool,
+            #[export]
+            activation_behavior: ActivationBehavior,
+            // Places the followers at the target when the scene starts, instead of sliding them there
+            // from where they were put in the editor. The additive mode always starts from no offset.
+            #[export]
+            snap_on_ready: bool,
+            initialized: bool,
+            // Set by stop, which pauses the animator without touching active.
+            stopped: bool,
 
             #[export]
             #[var(get, set = set_period)]
             period: f32,
+            // The systems use the period as the natural frequency in Hz, so this is the same value
+            // under the name of what it actually is. Setting either of them updates the other.
+            #[export]
+            #[var(get, set = set_frequency)]
+            frequency: f32,
             #[export]
             #[var(get, set = set_damping)]
             damping: f32,
             #[export]
             #[var(get, set = set_response)]
             response: f32,
+            // Declared after the values it sets, so loading a scene doesn't reset it to Custom.
+            #[export]
+            #[var(get, set = set_preset)]
+            preset: InterpolationPreset,
+            #[export]
+            #[var(get, set = set_parameters)]
+            parameters: Option<Gd<SecondOrderParameters>>,
+            // Follows the AnimatorDefaults node of the tree, unless the parameters resource is set.
+            #[export]
+            #[var(get, set = set_use_global_defaults)]
+            use_global_defaults: bool,
+            global_defaults: Option<Gd<AnimatorDefaults>>,
+            global_values: Option<(f32, f32, f32)>,
+            #[export]
+            #[var(get, set = set_clamp_unstable_params)]
+            clamp_unstable_params: bool,
+            #[export]
+            #[var(get, set = set_integration_method)]
+            integration_method: IntegrationMethod,
+            #[export]
+            #[var(get, set = set_analytic_when_static)]
+            analytic_when_static: bool,
+
+            #[export]
+            settle_threshold: f32,
+            settled: bool,
+            #[export]
+            #[var(get, set = set_auto_sleep)]
+            auto_sleep: bool,
+            #[export]
+            sleep_threshold: f32,
+            sleeping: bool,
+            sleep_input: $system_inner_type,
+            #[export]
+            emit_value_changed: bool,
+            #[export]
+            overshoot_threshold: f32,
+            peak_overshoot: f32,
+            overshoot_reported: bool,
+            overshoot_start: $system_inner_type,
+            overshoot_target: $system_inner_type,
+            #[export]
+            deadzone: f32,
+            // The target moving further than this in a single frame is snapped to. Zero disables it.
+            #[export]
+            teleport_threshold: f32,
+            last_input: $system_inner_type,
+
+            #[export]
+            warmup_seconds: f32,
+            #[export]
+            #[var(get, set = set_time_scale)]
+            time_scale: f32,
+            #[export]
+            ignore_engine_time_scale: bool,
+            // 0 disables the limit.
+            #[export]
+            max_delta: f32,
+            #[export]
+            split_large_delta: bool,
+            #[export]
+            substeps: u32,
+
+            // Can't change while the game runs, so it's not queried from the engine every frame.
+            is_editor: bool,
+            has_warned: bool,
+
+            // Draws a line from the follower to the target in the editor viewport.
+            #[export]
+            #[var(get, set = set_draw_debug)]
+            draw_debug: bool,
+            debug_line: DebugLine,
+            // An inspector button: checking it in the editor snaps the follower, it's never stored.
+            #[export]
+            #[var(get, set = set_snap_follower_to_target)]
+            snap_follower_to_target: bool,
+
+            system: $system_type,
+            // One system per entry of followers, so every follower keeps its own state.
+            follower_systems: Vec<$system_type>,
+
+            $(
+                #[export]
+                $global_flag: bool,
+            )?
+            $(
+                #[export]
+                axis_mask: $axis_mask_type,
+            )?
+            $(
+                #[export]
+                enable_clamp: bool,
+                #[export]
+                clamp_min: $clamp_type,
+                #[export]
+                clamp_max: $clamp_type,
+            )?
+            $(
+                #[export]
+                #[var(get, set = set_anisotropic)]
+                anisotropic: bool,
+                #[export]
+                #[var(get, set = set_anisotropic_period)]
+                anisotropic_period: $anisotropic_type,
+                #[export]
+                #[var(get, set = set_anisotropic_damping)]
+                anisotropic_damping: $anisotropic_type,
+                #[export]
+                #[var(get, set = set_anisotropic_response)]
+                anisotropic_response: $anisotropic_type,
+            )?
+            $(
+                #[export]
+                #[var(get, set = $set_speed_limit)]
+                $speed_limit: f32,
+            )?
+            $(
+                #[export]
+                use_velocity_source: bool,
+                #[export]
+                velocity_source: Option<Gd<$velocity_source_type>>,
+            )?
+            $( $( $(#[$field_attr])* $field: $field_type, )* )?
+
+            base: Base<Node>,
+        }
+
+        #[godot_api]
+        impl $node_name {
+            #[signal]
+            fn settled();
+            #[signal]
+            fn moving();
+            #[signal]
+            fn value_changed(value: Variant);
+            #[signal]
+            fn overshot();
+            #[signal]
+            fn target_lost();
+            #[signal]
+            fn woke();
+            #[signal]
+            fn slept();
+
+            #[func]
+            fn set_active(&mut self, value: bool) {
+                if self.active != value {
+                    self.active = value;
+                }
+
+                if self.active && self._validate().is_ok() {
+                    self._activate();
+                }
+            }
+            #[func]
+            fn set_run_in_editor(&mut self, value: bool) {
+                if self.run_in_editor != value {
+                    self.run_in_editor = value;
+                }
+
+                if self.active && self._validate().is_ok() {
+                    self._activate();
+                }
+            }
+            #[func]
+            fn set_follower(&mut self, value: Option<Gd<$node_type>>) {
+                self.follower = value;
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_followers(&mut self, value: Array<Gd<$node_type>>) {
+                self.followers = value;
+                self._reset_follower_systems();
+                self.base_mut().update_configuration_warnings();
+
+                if self.active && self._validate().is_ok() {
+                    self._update_initial_values();
+                }
+            }
+            #[func]
+            fn set_target(&mut self, value: Option<Gd<$node_type>>) {
+                self.target = value;
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_follower_path(&mut self, value: NodePath) {
+                self.follower_path = value;
+                self._resolve_paths();
+            }
+            #[func]
+            fn set_target_path(&mut self, value: NodePath) {
+                self.target_path = value;
+                self._resolve_paths();
+            }
+            #[func]
+            fn set_target_group(&mut self, value: StringName) {
+                self.target_group = value;
+                self._resolve_paths();
+            }
+            #[func]
+            fn set_target_source(&mut self, value: TargetSource) {
+                self.target_source = value;
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_mode(&mut self, value: AnimationMode) {
+                self.mode = value;
+
+                // The system works on the value in one mode and on the offset in the other.
+                if self.active && self._validate().is_ok() {
+                    self._update_initial_values();
+                }
+            }
+            #[func]
+            fn set_auto_sleep(&mut self, value: bool) {
+                self.auto_sleep = value;
+
+                if !self.auto_sleep && self.sleeping {
+                    self._wake();
+                }
+            }
+            #[func]
+            fn set_time_scale(&mut self, value: f32) {
+                if value < 0.0 {
+                    godot_warn!(
+                        "Animator warning: The time scale {} is negative, 0 is used instead.",
+                        value
+                    );
+                }
+
+                self.time_scale = value.max(0.0);
+            }
+            #[func]
+            fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+                if self.interpolation_mode != value {
+                    self.interpolation_mode = value;
+                }
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_period(&mut self, value: f32) {
+                self.period = value;
+                self.frequency = self.period;
+                self.preset = InterpolationPreset::Custom;
+                self._apply_parameters();
+            }
+            #[func]
+            fn set_frequency(&mut self, value: f32) {
+                self.set_period(value);
+            }
+            #[func]
+            fn set_damping(&mut self, value: f32) {
+                self.damping = value;
+                self.preset = InterpolationPreset::Custom;
+                self._apply_parameters();
+            }
+            #[func]
+            fn set_response(&mut self, value: f32) {
+                self.response = value;
+                self.preset = InterpolationPreset::Custom;
+                self._apply_parameters();
+            }
+            /// Sets the three parameters at once, recomputing the systems a single time.
+            #[func]
+            fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+                self.period = period;
+                self.frequency = self.period;
+                self.damping = damping;
+                self.response = response;
+                self.preset = InterpolationPreset::Custom;
+                self._apply_parameters();
+            }
+            $(
+                #[func]
+                fn $set_speed_limit(&mut self, value: f32) {
+                    self.$speed_limit = value.max(0.0);
+                    self._apply_parameters();
+                }
+            )?
+            $(
+                #[func]
+                fn set_anisotropic(&mut self, value: bool) {
+                    self.anisotropic = value;
+                    self._apply_parameters();
+                }
+                #[func]
+                fn set_anisotropic_period(&mut self, value: $anisotropic_type) {
+                    self.anisotropic_period = value;
+                    self._apply_parameters();
+                }
+                #[func]
+                fn set_anisotropic_damping(&mut self, value: $anisotropic_type) {
+                    self.anisotropic_damping = value;
+                    self._apply_parameters();
+                }
+                #[func]
+                fn set_anisotropic_response(&mut self, value: $anisotropic_type) {
+                    self.anisotropic_response = value;
+                    self._apply_parameters();
+                }
+            )?
+            #[func]
+            fn set_preset(&mut self, value: InterpolationPreset) {
+                self.preset = value;
+
+                if let Some((period, damping, response)) = self.preset.parameters() {
+                    self.period = period;
+                    self.frequency = self.period;
+                    self.damping = damping;
+                    self.response = response;
+                    self._apply_parameters();
+                }
+            }
+            #[func]
+            fn set_parameters(&mut self, value: Option<Gd<SecondOrderParameters>>) {
+                let callable = Callable::from_object_method(&self.to_gd(), "_on_parameters_changed");
+
+                if let Some(mut parameters) = self.parameters.take() {
+                    if parameters.is_connected("changed".into(), callable.clone()) {
+                        parameters.disconnect("changed".into(), callable.clone());
+                    }
+                }
+
+                if let Some(mut parameters) = value.clone() {
+                    parameters.connect("changed".into(), callable);
+                }
+
+                self.parameters = value;
+                self._apply_parameters();
+            }
+            #[func]
+            fn set_use_global_defaults(&mut self, value: bool) {
+                self.use_global_defaults = value;
+                self._connect_global_defaults();
+            }
+            #[func]
+            fn set_draw_debug(&mut self, value: bool) {
+                self.draw_debug = value;
+
+                if !self.draw_debug {
+                    self.debug_line.clear();
+                }
+            }
+            #[func]
+            fn set_snap_follower_to_target(&mut self, value: bool) {
+                // Previews the settled pose without running the scene.
+                if value && self.is_editor {
+                    self.snap_to_target();
+                }
+            }
+            #[func]
+            fn set_clamp_unstable_params(&mut self, value: bool) {
+                self.clamp_unstable_params = value;
+                self._apply_parameters();
+            }
+            #[func]
+            fn set_integration_method(&mut self, value: IntegrationMethod) {
+                self.integration_method = value;
+                self._apply_parameters();
+            }
+            #[func]
+            fn set_analytic_when_static(&mut self, value: bool) {
+                self.analytic_when_static = value;
+                self._apply_parameters();
+            }
+            #[func]
+            fn _on_parameters_changed(&mut self) {
+                self._apply_parameters();
+            }
+            #[func]
+            fn _on_global_defaults_changed(&mut self, period: f32, damping: f32, response: f32) {
+                self.global_values = Some((period, damping, response));
+                self._apply_parameters();
+            }
+
+            /// Subscribes to the AnimatorDefaults node of the tree while use_global_defaults is on.
+            fn _connect_global_defaults(&mut self) {
+                let callable =
+                    Callable::from_object_method(&self.to_gd(), "_on_global_defaults_changed");
+
+                if let Some(mut defaults) = self.global_defaults.take().filter(|defaults| defaults.is_instance_valid()) {
+                    if defaults.is_connected("defaults_changed".into(), callable.clone()) {
+                        defaults.disconnect("defaults_changed".into(), callable.clone());
+                    }
+                }
+                self.global_values = None;
+
+                if self.use_global_defaults && self.base().is_inside_tree() {
+                    let defaults = self
+                        .base()
+                        .get_tree()
+                        .and_then(|mut tree| tree.get_first_node_in_group(DEFAULTS_GROUP.into()))
+                        .and_then(|node| node.try_cast::<AnimatorDefaults>().ok());
+
+                    if let Some(mut defaults) = defaults {
+                        defaults.connect("defaults_changed".into(), callable);
+                        self.global_values = Some(defaults.bind().values());
+                        self.global_defaults = Some(defaults);
+                    }
+                }
+
+                self._apply_parameters();
+            }
+
+            /// Copies the tuning of another animator, e.g. a sibling that already feels right.
+            #[func]
+            fn copy_settings_from(&mut self, other: Gd<Node>) {
+                let settings = (
+                    other.get("period".into()).try_to::<f32>(),
+                    other.get("damping".into()).try_to::<f32>(),
+                    other.get("response".into()).try_to::<f32>(),
+                    other.get("interpolation_mode".into()).try_to::<InterpolationMode>(),
+                    other.get("active".into()).try_to::<bool>(),
+                );
+
+                let (Ok(period), Ok(damping), Ok(response), Ok(interpolation_mode), Ok(active)) =
+                    settings
+                else {
+                    let err = AnimatorError::NotAnAnimator(other.get_name().to_string());
+                    godot_warn!("Animator error: {}", err);
+                    return;
+                };
+
+                self.set_period(period);
+                self.set_damping(damping);
+                self.set_response(response);
+                self.set_interpolation_mode(interpolation_mode);
+                self.set_active(active);
+            }
+
+            /// The parameters resource of the node, if any, takes precedence over the global defaults,
+            /// which take precedence over the inline values.
+            fn _effective_parameters(&self) -> (f32, f32, f32) {
+                let (period, damping, response) = match (self.parameters.as_ref(), self.global_values) {
+                    (Some(parameters), _) => parameters.bind().values(),
+                    (None, Some(values)) => values,
+                    (None, None) => (self.period, self.damping, self.response),
+                };
+
+                if self.clamp_unstable_params && !is_stable(period, damping, response) {
+                    return (period, damping.max(0.0), response);
+                }
+
+                (period, damping, response)
+            }
+
+            fn _apply_parameters(&mut self) {
+                let (period, damping, response) = self._effective_parameters();
+                warn_on_period(period);
+
+                for system in std::iter::once(&mut self.system).chain(self.follower_systems.iter_mut()) {
+                    system.update_params(period, damping, response);
+                    system.update_integration_method(self.integration_method);
+                    system.update_analytic_when_static(self.analytic_when_static);
+                    $( system.update_max_speed(self.$speed_limit); )?
+                    $(
+                        let anisotropic_params: Option<(
+                            $anisotropic_type,
+                            $anisotropic_type,
+                            $anisotropic_type,
+                        )> = self.anisotropic.then_some((
+                            self.anisotropic_period,
+                            self.anisotropic_damping,
+                            self.anisotropic_response,
+                        ));
+                        match anisotropic_params {
+                            Some((period, damping, response)) => {
+                                system.update_anisotropic_params(period, damping, response)
+                            }
+                            None => system.clear_anisotropic_params(),
+                        }
+                    )?
+                }
+            }
+
+            /// Makes the system start over from the current follower and target values,
+            /// clearing the velocity. Useful after teleporting the follower or the target.
+            #[func]
+            fn reset(&mut self) {
+                if let Err(err) = self._validate() {
+                    godot_warn!("Animator error: {}", err);
+                    return;
+                }
+
+                self._update_initial_values();
+            }
+
+            /// Makes the follower the target and vice versa, starting over from their current values.
+            #[func]
+            fn swap_target_and_follower(&mut self) {
+                if self.follower.is_none() {
+                    godot_warn!("Animator error: {}", AnimatorError::NodeNotSpecified("follower"));
+                    return;
+                }
+                if self.target.is_none() {
+                    godot_warn!("Animator error: {}", AnimatorError::NodeNotSpecified("target"));
+                    return;
+                }
+                if !self._ensure_target() {
+                    return;
+                }
+
+                std::mem::swap(&mut self.follower, &mut self.target);
+                std::mem::swap(&mut self.follower_path, &mut self.target_path);
+
+                self._update_initial_values();
+            }
+
+            /// Moves the follower to the target and stops it, skipping the smoothing entirely.
+            #[func]
+            fn snap_to_target(&mut self) {
+                if let Err(err) = self._validate() {
+                    godot_warn!("Animator error: {}", err);
+                    return;
+                }
+                if !self._ensure_target() {
+                    return;
+                }
+
+                let value = self._get_target_value();
+                $( let value = $pre_process(&mut *self, value); )?
+
+                // In the additive mode the target is an offset, which is put on the baseline like in _update.
+                if let Some(mut follower) = self.follower.clone() {
+                    let baseline = self._additive_baseline(&follower, &self.system);
+                    self.system.snap_to(value);
+                    let output = Self::_apply_baseline(baseline, value);
+                    $( let output = $post_process(&mut *self, output, output); )?
+                    self._set_node_value(&mut follower, output);
+                }
+
+                if self.follower_systems.len() != self.followers.len() {
+                    self._reset_follower_systems();
+                }
+
+                let followers: Vec<Gd<$node_type>> = self.followers.iter_shared().collect();
+                for (index, mut follower) in followers.into_iter().enumerate() {
+                    let baseline = self._additive_baseline(&follower, &self.follower_systems[index]);
+                    self.follower_systems[index].snap_to(value);
+                    let output = Self::_apply_baseline(baseline, value);
+                    $( let output = $post_process(&mut *self, output, output); )?
+                    self._set_node_value(&mut follower, output);
+                }
+            }
+
+            /// The velocity of the follower per second. Rotations report the angular velocity
+            /// in radians per second. Colors in the Hsv color space report the rate of change
+            /// of h, s, v in r, g, b instead of an RGB rate. It's in the units initial_velocity
+            /// takes, so it can be handed over to another animator as is.
+            #[func]
+            fn get_velocity(&self) -> <$system_inner_type as SystemVelocity>::Velocity {
+                self.system.velocity()
+            }
+
+            /// The smoothed value of the follower, as of the last update.
+            #[func]
+            fn get_current_value(&self) -> $system_inner_type {
+                self.system.current()
+            }
+
+            /// The internal state of the animation and its parameters, e.g. for save games.
+            #[func]
+            fn save_state(&self) -> Dictionary {
+                let followers: Array<Dictionary> =
+                    self.follower_systems.iter().map(Self::_save_system_state).collect();
+
+                let mut state = Self::_save_system_state(&self.system);
+                state.set("followers", followers);
+                state.set("period", self.period);
+                state.set("damping", self.damping);
+                state.set("response", self.response);
+                state
+            }
+
+            /// Restores a state returned by save_state. The follower values are not read again,
+            /// so the animation continues exactly where it was saved.
+            #[func]
+            fn load_state(&mut self, state: Dictionary) {
+                if let Err(err) = self._load_state(&state) {
+                    godot_warn!("Animator error: {}", err);
+                }
+            }
+
+            fn _load_state(&mut self, state: &Dictionary) -> Result<(), AnimatorError> {
+                let parameters = (
+                    state.get("period").and_then(|value| value.try_to::<f32>().ok()),
+                    state.get("damping").and_then(|value| value.try_to::<f32>().ok()),
+                    state.get("response").and_then(|value| value.try_to::<f32>().ok()),
+                );
+                let (Some(period), Some(damping), Some(response)) = parameters else {
+                    return Err(AnimatorError::InvalidState);
+                };
+
+                self.period = period;
+                self.frequency = self.period;
+                self.damping = damping;
+                self.response = response;
+
+                if self.follower_systems.len() != self.followers.len() {
+                    self._reset_follower_systems();
+                }
+                self._apply_parameters();
+
+                Self::_load_system_state(&mut self.system, state)?;
+
+                let followers = state
+                    .get("followers")
+                    .and_then(|value| value.try_to::<Array<Dictionary>>().ok())
+                    .unwrap_or_default();
+                for (system, state) in self.follower_systems.iter_mut().zip(followers.iter_shared()) {
+                    Self::_load_system_state(system, &state)?;
+                }
+
+                Ok(())
+            }
+
+            fn _save_system_state(system: &$system_type) -> Dictionary {
+                let (xp, y, yd) = system.state();
+
+                let mut state = Dictionary::new();
+                state.set("xp", xp.to_state());
+                state.set("y", y.to_state());
+                state.set("yd", yd.to_state());
+                state
+            }
+
+            fn _load_system_state(
+                system: &mut $system_type,
+                state: &Dictionary,
+            ) -> Result<(), AnimatorError> {
+                let read = |key: &str| {
+                    state
+                        .get(key)
+                        .and_then(|value| <$system_inner_type>::from_state(&value))
+                };
+
+                let (Some(xp), Some(y), Some(yd)) = (read("xp"), read("y"), read("yd")) else {
+                    return Err(AnimatorError::InvalidState);
+                };
+
+                system.restore_state(xp, y, yd);
+                Ok(())
+            }
+
+            /// Pauses the animation, keeping the state of the systems. Unlike setting active to false,
+            /// active stays as authored and start continues exactly where the animation stopped,
+            /// while reactivating starts over from the current values, unless activation_behavior is Resume.
+            #[func]
+            fn stop(&mut self) {
+                self.stopped = true;
+            }
+
+            /// Continues the animation paused with stop.
+            #[func]
+            fn start(&mut self) {
+                self.stopped = false;
+            }
+
+            #[func]
+            fn is_stopped(&self) -> bool {
+                self.stopped
+            }
+
+            /// The coefficients of the system, derived from the period, damping and response.
+            #[func]
+            fn get_coefficients(&self) -> Vector3 {
+                let (k0, k1, k2) = self.system.coefficients();
+                Vector3::new(k0, k1, k2)
+            }
+
+            /// Whether every follower has reached the target and almost stopped, within settle_threshold.
+            #[func]
+            fn is_settled(&self) -> bool {
+                std::iter::once(&self.system)
+                    .chain(self.follower_systems.iter())
+                    .all(|system| system.is_settled(self.settle_threshold, self.settle_threshold))
+            }
+
+            fn _get_target_value(&self) -> $system_inner_type {
+                let value = match self.target_source {
+                    TargetSource::Node => self._get_node_value(self.target.as_ref().unwrap()),
+                    TargetSource::Value => self.target_value,
+                    TargetSource::Mouse => self._get_mouse_value(),
+                };
+
+                value.offset_by(self.offset)
+            }
+
+            fn _mouse_getter(
+            ) -> Option<fn(&Self, &Gd<$node_type>) -> Option<$system_inner_type>> {
+                None $(
+                    .or(Some(
+                        $get_mouse_value as fn(&Self, &Gd<$node_type>) -> Option<$system_inner_type>
+                    ))
+                )?
+            }
+
+            /// The physics space the mouse is found in can only be queried during the physics process.
+            fn _mouse_in_physics() -> bool {
+                false $( || $mouse_in_physics )?
+            }
+
+            /// The follower stays where it is while the mouse position is unknown, e.g. without a camera.
+            fn _get_mouse_value(&self) -> $system_inner_type {
+                let Some(follower) = self.follower.clone().or_else(|| self.followers.iter_shared().next()) else {
+                    return self.target_value;
+                };
+
+                Self::_mouse_getter()
+                    .and_then(|get_mouse_value| get_mouse_value(self, &follower))
+                    .unwrap_or_else(|| self._get_node_value(&follower))
+            }
+
+            fn _reset_follower_systems(&mut self) {
+                let (period, damping, response) = self._effective_parameters();
+
+                self.follower_systems = (0..self.followers.len())
+                    .map(|_| <$system_type>::new(period, damping, response))
+                    .collect();
+                self._apply_parameters();
+            }
+
+            /// Resolves the follower and target paths, which are relative to the animator,
+            /// into the references. It's only possible once the animator is in the tree.
+            fn _resolve_paths(&mut self) {
+                if !self.base().is_inside_tree() {
+                    return;
+                }
+
+                if !self.follower_path.is_empty() {
+                    match self._resolve_path(self.follower_path.clone()) {
+                        Some(follower) => self.follower = Some(follower),
+                        None => godot_warn!(
+                            "Animator error: {}",
+                            AnimatorError::PathNotResolved("follower", self.follower_path.to_string())
+                        ),
+                    }
+                }
+                if !self.target_path.is_empty() {
+                    match self._resolve_path(self.target_path.clone()) {
+                        Some(target) => self.target = Some(target),
+                        None => godot_warn!(
+                            "Animator error: {}",
+                            AnimatorError::PathNotResolved("target", self.target_path.to_string())
+                        ),
+                    }
+                }
+                if self.target.is_none() && !self.target_group.is_empty() {
+                    match self._resolve_group(self.target_group.clone()) {
+                        Some(target) => self.target = Some(target),
+                        None => godot_warn!(
+                            "Animator error: {}",
+                            AnimatorError::GroupNotResolved("target", self.target_group.to_string())
+                        ),
+                    }
+                }
+
+                self.base_mut().update_configuration_warnings();
+            }
+
+            fn _resolve_group(&self, group: StringName) -> Option<Gd<$node_type>> {
+                let nodes: Vec<Gd<$node_type>> = self
+                    .base()
+                    .get_tree()?
+                    .get_nodes_in_group(group.clone())
+                    .iter_shared()
+                    .filter_map(|node| node.try_cast::<$node_type>().ok())
+                    .collect();
+
+                if nodes.len() > 1 {
+                    godot_warn!(
+                        "Animator warning: The group \"{}\" has {} nodes, the first one is used.",
+                        group,
+                        nodes.len()
+                    );
+                }
+
+                nodes.into_iter().next()
+            }
+
+            fn _resolve_path(&self, path: NodePath) -> Option<Gd<$node_type>> {
+                self.base()
+                    .get_node_or_null(path)?
+                    .try_cast::<$node_type>()
+                    .ok()
+            }
+
+            /// Resuming is only possible once the systems have been started from the follower values.
+            fn _activate(&mut self) {
+                if self.activation_behavior == ActivationBehavior::Resume && self.initialized {
+                    return;
+                }
+
+                self._update_initial_values();
+            }
+
+            fn _update_initial_values(&mut self) {
+                // Every path that starts the systems over comes here, and the target may be freed
+                // since the last frame, when only the per-frame path has checked it.
+                if !self._ensure_target() {
+                    return;
+                }
+
+                self.initialized = true;
+
+                let previous = self._get_target_value();
+                $( let previous = $pre_process(&mut *self, previous); )?
+                self.last_input = previous;
+
+                if let Some(follower) = self.follower.clone() {
+                    let current = self._get_initial_value(&follower);
+                    $( let current = $pre_process(&mut *self, current); )?
+
+                    self.system.update_initial_values(previous, current, self._initial_derivative());
+                }
+
+                if self.follower_systems.len() != self.followers.len() {
+                    self._reset_follower_systems();
+                }
+
+                let followers: Vec<Gd<$node_type>> = self.followers.iter_shared().collect();
+                for (index, follower) in followers.iter().enumerate() {
+                    let current = self._get_initial_value(follower);
+                    $( let current = $pre_process(&mut *self, current); )?
+
+                    let initial_derivative = self._initial_derivative();
+                    self.follower_systems[index].update_initial_values(
+                        previous,
+                        current,
+                        initial_derivative,
+                    );
+                }
+            }
+
+            /// The additive mode starts from no offset, on top of the current value of the follower.
+            fn _get_initial_value(&self, follower: &Gd<$node_type>) -> $system_inner_type {
+                match self.mode {
+                    AnimationMode::Absolute => self._get_node_value(follower),
+                    AnimationMode::Additive => <$system_inner_type>::no_offset(),
+                }
+            }
+
+            /// The value the offset is put on in the additive mode: the value of the follower
+            /// without the offset applied last frame, so changes by other drivers are kept.
+            fn _additive_baseline(
+                &self,
+                follower: &Gd<$node_type>,
+                system: &$system_type,
+            ) -> Option<$system_inner_type> {
+                match self.mode {
+                    AnimationMode::Absolute => None,
+                    AnimationMode::Additive => {
+                        Some(self._get_node_value(follower).remove_offset(system.current()))
+                    }
+                }
+            }
+
+            /// Puts the value on the baseline in the additive mode.
+            fn _apply_baseline(
+                baseline: Option<$system_inner_type>,
+                value: $system_inner_type,
+            ) -> $system_inner_type {
+                match baseline {
+                    Some(baseline) => baseline.offset_by(value),
+                    None => value,
+                }
+            }
+
+            /// Snaps the followers to the target, keeping the initial velocity so they can still be flicked.
+            fn _snap_on_ready(&mut self) {
+                self.snap_to_target();
+
+                let initial_derivative = self._initial_derivative();
+                for system in std::iter::once(&mut self.system).chain(self.follower_systems.iter_mut()) {
+                    let (previous, current, _) = system.state();
+                    system.restore_state(previous, current, initial_derivative);
+                }
+            }
+
+            /// The initial velocity as the derivative the systems store, e.g. in the log space for rotations.
+            fn _initial_derivative(&self) -> $system_inner_type {
+                <$system_inner_type as SystemVelocity>::from_velocity(self.initial_velocity)
+            }
+
+            /// Runs the animation for warmup_seconds in small steps,
+            /// so the follower starts already converged to the target.
+            fn _warmup(&mut self) {
+                if self.warmup_seconds <= 0.0 {
+                    return;
+                }
+
+                let steps = (self.warmup_seconds as f64 / WARMUP_STEP).ceil() as u32;
+                for _ in 0..steps {
+                    self._update(WARMUP_STEP);
+                }
+            }
+
+            /// Undoes the scaling of the process delta by Engine.time_scale. Nothing can be recovered
+            /// when the time scale is zero, as the delta is zero as well then.
+            fn _unscaled_delta(delta: f64) -> f64 {
+                let time_scale = Engine::singleton().get_time_scale();
+                if time_scale <= 0.0 {
+                    return delta;
+                }
+
+                delta / time_scale
+            }
+
+            /// Advances the animation by the process delta, scaled by time_scale.
+            fn _advance(&mut self, delta: f64) {
+                let delta = delta * self.time_scale as f64;
+                let delta = if self.ignore_engine_time_scale {
+                    Self::_unscaled_delta(delta)
+                } else {
+                    delta
+                };
+
+                self._step_limited(delta);
+            }
+
+            /// Steps the animation, limiting the delta to max_delta unless it is 0. When
+            /// split_large_delta is set, a larger delta is split into equal steps no longer than
+            /// max_delta instead.
+            fn _step_limited(&mut self, delta: f64) {
+                let max_delta = self.max_delta as f64;
+                if max_delta <= 0.0 || delta <= max_delta {
+                    self._update(delta);
+                    return;
+                }
+
+                if !self.split_large_delta {
+                    self._update(max_delta);
+                    return;
+                }
+
+                let delta = delta.min(max_delta * MAX_SPLIT_STEPS as f64);
+                let steps = (delta / max_delta).ceil();
+                for _ in 0..steps as u32 {
+                    self._update(delta / steps);
+                }
+            }
+
+            fn _update(&mut self, delta: f64) {
+                if !self._ensure_target() {
+                    return;
+                }
+
+                let input = self._get_target_value();
+                $( let input = $pre_process(&mut *self, input); )?
+                // A target outside of the range is followed to the bound, so the follower can settle there.
+                $(
+                    let input: $clamp_type = if self.enable_clamp {
+                        input.clamp_components(self.clamp_min, self.clamp_max)
+                    } else {
+                        input
+                    };
+                )?
+                let input = self._apply_deadzone(input);
+                self._track_overshoot_target(input);
+
+                let input_velocity = None;
+                $( let input_velocity = self._get_input_velocity($velocity_property); )?
+
+                // The largest distance to the target or speed among all the followers.
+                let mut error: f32 = 0.0;
+                // The value of the follower, or of the first one in followers if it's not set.
+                let mut emitted_value: Option<$system_inner_type> = None;
+
+                if let Some(mut follower) = self.follower.clone() {
+                    let baseline = self._additive_baseline(&follower, &self.system);
+                    let output = Self::_step_system(
+                        &mut self.system,
+                        input,
+                        delta,
+                        input_velocity,
+                        self.substeps,
+                        self.teleport_threshold,
+                    );
+                    $(
+                        let output: $clamp_type = if self.enable_clamp {
+                            Self::_clamp_system(&mut self.system, self.clamp_min, self.clamp_max)
+                        } else {
+                            output
+                        };
+                    )?
+                    error = error.max(output.distance(input)).max(self.system.speed());
+                    let output = Self::_apply_baseline(baseline, output);
+                    $( let output = $post_process(&mut *self, Self::_apply_baseline(baseline, input), output); )?
+                    $(
+                        let output = <$axis_mask_type>::apply_axis_mask(
+                            output,
+                            Self::_apply_baseline(baseline, input),
+                            self.axis_mask,
+                        );
+                    )?
+                    self._set_node_value(&mut follower, output);
+                    emitted_value = Some(output);
+                }
+
+                // The array can be changed in place from a script, bypassing set_followers.
+                if self.follower_systems.len() != self.followers.len() {
+                    self._reset_follower_systems();
+                    self._update_initial_values();
+                }
+
+                let followers: Vec<Gd<$node_type>> = self.followers.iter_shared().collect();
+                for (index, mut follower) in followers.into_iter().enumerate() {
+                    let baseline = self._additive_baseline(&follower, &self.follower_systems[index]);
+                    let output = Self::_step_system(
+                        &mut self.follower_systems[index],
+                        input,
+                        delta,
+                        input_velocity,
+                        self.substeps,
+                        self.teleport_threshold,
+                    );
+                    $(
+                        let output: $clamp_type = if self.enable_clamp {
+                            Self::_clamp_system(
+                                &mut self.follower_systems[index],
+                                self.clamp_min,
+                                self.clamp_max,
+                            )
+                        } else {
+                            output
+                        };
+                    )?
+                    let speed = self.follower_systems[index].speed();
+                    error = error.max(output.distance(input)).max(speed);
+                    let output = Self::_apply_baseline(baseline, output);
+                    $( let output = $post_process(&mut *self, Self::_apply_baseline(baseline, input), output); )?
+                    $(
+                        let output = <$axis_mask_type>::apply_axis_mask(
+                            output,
+                            Self::_apply_baseline(baseline, input),
+                            self.axis_mask,
+                        );
+                    )?
+                    self._set_node_value(&mut follower, output);
+                    emitted_value.get_or_insert(output);
+                }
+
+                if let Some(value) = emitted_value {
+                    self._measure_overshoot(value);
+                }
+
+                if self.emit_value_changed {
+                    if let Some(value) = emitted_value {
+                        self.base_mut()
+                            .emit_signal("value_changed".into(), &[value.to_variant()]);
+                    }
+                }
+
+                self._update_settled(error < self.settle_threshold);
+
+                if self.auto_sleep && error < self.sleep_threshold {
+                    self._sleep(input);
+                }
+            }
+
+            /// How far the follower went past the target since the target last changed,
+            /// along the direction it approached the target from. Rotations report radians.
+            #[func]
+            fn get_peak_overshoot(&self) -> f32 {
+                self.peak_overshoot
+            }
+
+            /// Starts a new measurement when the target changes, from where the follower is now.
+            fn _track_overshoot_target(&mut self, input: $system_inner_type) {
+                if input.distance(self.overshoot_target) <= OVERSHOOT_TARGET_EPSILON {
+                    return;
+                }
+
+                let start = if self.follower.is_some() {
+                    Some(self.system.current())
+                } else {
+                    self.follower_systems.first().map(|system| system.current())
+                };
+
+                self.overshoot_start = start.unwrap_or(input);
+                self.overshoot_target = input;
+                self.peak_overshoot = 0.0;
+                self.overshoot_reported = false;
+            }
+
+            fn _measure_overshoot(&mut self, value: $system_inner_type) {
+                let overshoot = value.overshoot(self.overshoot_target, self.overshoot_start);
+                self.peak_overshoot = self.peak_overshoot.max(overshoot);
+
+                if !self.overshoot_reported && overshoot > self.overshoot_threshold {
+                    self.overshoot_reported = true;
+                    self.base_mut().emit_signal("overshot".into(), &[]);
+                }
+            }
+
+            /// Ignores target changes smaller than the deadzone, e.g. sensor or network jitter,
+            /// so the follower can settle completely.
+            fn _apply_deadzone(&mut self, input: $system_inner_type) -> $system_inner_type {
+                if self.deadzone > 0.0 && input.distance(self.last_input) < self.deadzone {
+                    return self.last_input;
+                }
+
+                self.last_input = input;
+                input
+            }
+
+            /// Steps the system substeps times with an equal part of the delta,
+            /// which is more stable and accurate for snappy systems at low framerates.
+            /// A target that jumped further than the teleport threshold is snapped to instead.
+            fn _step_system(
+                system: &mut $system_type,
+                input: $system_inner_type,
+                delta: f64,
+                input_velocity: Option<<$system_inner_type as SystemVelocity>::Velocity>,
+                substeps: u32,
+                teleport_threshold: f32,
+            ) -> $system_inner_type {
+                if teleport_threshold > 0.0 && input.distance(system.previous_input()) > teleport_threshold {
+                    system.snap_to(input);
+                    return input;
+                }
+
+                let substeps = substeps.max(1);
+                let delta = delta / substeps as f64;
+
+                let mut output = input;
+                for _ in 0..substeps {
+                    output = match input_velocity {
+                        Some(input_velocity) => system.update_with_velocity(input, input_velocity, delta),
+                        None => system.update(input, delta),
+                    };
+                }
+                warn_on_recovery(system.take_recovered());
+
+                output
+            }
+
+            $(
+                /// The velocity of the target read from the velocity source, if it's used.
+                fn _get_input_velocity(
+                    &self,
+                    property: &str,
+                ) -> Option<<$system_inner_type as SystemVelocity>::Velocity> {
+                    if !self.use_velocity_source {
+                        return None;
+                    }
+
+                    let velocity_source: &Gd<$velocity_source_type> = self.velocity_source.as_ref()?;
+                    velocity_source
+                        .get(property.into())
+                        .try_to::<<$system_inner_type as SystemVelocity>::Velocity>()
+                        .ok()
+                }
+            )?
+
+            $(
+                /// Clamps the output of the system and its velocity along with it,
+                /// so the follower doesn't keep pushing into the bound and shoot off once it's freed.
+                fn _clamp_system(
+                    system: &mut $system_type,
+                    min: $clamp_type,
+                    max: $clamp_type,
+                ) -> $clamp_type {
+                    let (xp, y, yd) = system.state();
+                    let (y, yd) = y.clamp_with_velocity(yd, min, max);
+                    system.restore_state(xp, y, yd);
+                    y
+                }
+            )?
+
+            /// Stops updating the systems. Only the callback that is not used for the interpolation
+            /// keeps running, watching the target to wake the animator up once it moves.
+            fn _sleep(&mut self, input: $system_inner_type) {
+                // Manual advance and the warmup keep updating a sleeping animator.
+                if self.sleeping {
+                    return;
+                }
+
+                self.sleeping = true;
+                self.sleep_input = input;
+
+                match self.interpolation_mode {
+                    InterpolationMode::Process => {
+                        self.base_mut().set_process(false);
+                        self.base_mut().set_physics_process(true);
+                    }
+                    InterpolationMode::Physics => {
+                        self.base_mut().set_physics_process(false);
+                        self.base_mut().set_process(true);
+                    }
+                    // The engine callbacks don't update the animator in this mode, there is nothing to switch.
+                    InterpolationMode::Manual => {}
+                }
+
+                self.base_mut().emit_signal("slept".into(), &[]);
+            }
+
+            fn _wake(&mut self) {
+                if !self.sleeping {
+                    return;
+                }
+
+                self.sleeping = false;
+
+                self.base_mut().set_process(true);
+                self.base_mut().set_physics_process(true);
+                self.base_mut().emit_signal("woke".into(), &[]);
+            }
+
+            fn _watch_target(&mut self) {
+                if !self._ensure_target() {
+                    return;
+                }
+
+                let input = self._get_target_value();
+                $( let input = $pre_process(&mut *self, input); )?
+
+                if input.distance(self.sleep_input) > self.sleep_threshold * WAKE_THRESHOLD_FACTOR {
+                    self._wake();
+                }
+            }
+
+            /// Sets the followers to where their systems will be after the time passed since the last
+            /// physics step. Only the followers are moved, the systems are stepped by the physics process.
+            fn _render_interpolated(&mut self) {
+                // The additive mode reads the follower back, it would pick the extrapolation up.
+                if self.mode != AnimationMode::Absolute {
+                    return;
+                }
 
-            system: $system_type,
+                let engine = Engine::singleton();
+                let step = 1.0 / engine.get_physics_ticks_per_second() as f64;
+                let time =
+                    (engine.get_physics_interpolation_fraction() * step) as f32 * self.time_scale;
 
-            base: Base<Node>,
-        }
+                if let Some(mut follower) = self.follower.clone() {
+                    let output = self.system.extrapolate(time);
+                    $(
+                        let input = self.last_input;
+                        let output = $post_process(&mut *self, input, output);
+                    )?
+                    $(
+                        let output =
+                            <$axis_mask_type>::apply_axis_mask(output, self.last_input, self.axis_mask);
+                    )?
+                    self._set_node_value(&mut follower, output);
+                }
 
-        #[godot_api]
-        impl $node_name {
-            #[func]
-            fn set_active(&mut self, value: bool) {
-                if self.active != value {
-                    self.active = value;
+                if self.follower_systems.len() != self.followers.len() {
+                    return;
                 }
 
-                if self.active && self._validate().is_ok() {
-                    self._update_initial_values();
+                let followers: Vec<Gd<$node_type>> = self.followers.iter_shared().collect();
+                for (index, mut follower) in followers.into_iter().enumerate() {
+                    let output = self.follower_systems[index].extrapolate(time);
+                    $(
+                        let input = self.last_input;
+                        let output = $post_process(&mut *self, input, output);
+                    )?
+                    $(
+                        let output =
+                            <$axis_mask_type>::apply_axis_mask(output, self.last_input, self.axis_mask);
+                    )?
+                    self._set_node_value(&mut follower, output);
                 }
             }
-            #[func]
-            fn set_run_in_editor(&mut self, value: bool) {
-                if self.run_in_editor != value {
-                    self.run_in_editor = value;
+
+            /// Whether the target can be read. A freed target is dropped and the animator is
+            /// deactivated, so scripts can assign another one on target_lost.
+            fn _ensure_target(&mut self) -> bool {
+                if self.target_source != TargetSource::Node {
+                    return true;
                 }
 
-                if self.active && self._validate().is_ok() {
-                    self._update_initial_values();
+                match self.target.as_ref() {
+                    Some(target) if target.is_instance_valid() => true,
+                    Some(_) => {
+                        self.target = None;
+                        self.active = false;
+                        self.base_mut().emit_signal("target_lost".into(), &[]);
+                        false
+                    }
+                    None => false,
                 }
             }
-            #[func]
-            fn set_interpolation_mode(&mut self, value: InterpolationMode) {
-                if self.interpolation_mode != value {
-                    self.interpolation_mode = value;
+
+            fn _draw_debug(&mut self) {
+                let nodes = self
+                    .follower
+                    .clone()
+                    .zip(self.target.clone())
+                    .filter(|(follower, target)| {
+                        follower.is_instance_valid() && target.is_instance_valid()
+                    });
+
+                match nodes {
+                    Some((follower, target)) => {
+                        let owner = self.to_gd().upcast::<Node>();
+                        self.debug_line.draw(&owner, follower.upcast(), target.upcast());
+                    }
+                    None => self.debug_line.clear(),
                 }
             }
-            #[func]
-            fn set_period(&mut self, value: f32) {
-                self.period = value;
-                self.system.update_period(self.period);
+
+            /// Emits settled or moving only when the state actually changes.
+            fn _update_settled(&mut self, settled: bool) {
+                if self.settled == settled {
+                    return;
+                }
+
+                self.settled = settled;
+
+                let signal = if settled { "settled" } else { "moving" };
+                self.base_mut().emit_signal(signal.into(), &[]);
             }
-            #[func]
-            fn set_damping(&mut self, value: f32) {
-                self.damping = value;
-                self.system.update_damping(self.damping);
+
+            fn _get_node_value(&self, node: &Gd<$node_type>) -> $system_inner_type {
+                $(
+                    if self.$global_flag {
+                        return $get_global_node_value(node);
+                    }
+                )?
+
+                $get_node_value(node)
+            }
+
+            fn _set_node_value(&self, node: &mut Gd<$node_type>, value: $system_inner_type) {
+                $(
+                    if self.$global_flag {
+                        $set_global_node_value(node, value);
+                        return;
+                    }
+                )?
+
+                $set_node_value(node, value);
             }
+
+            /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
             #[func]
-            fn set_response(&mut self, value: f32) {
-                self.response = value;
-                self.system.update_response(self.response);
+            fn advance(&mut self, delta: f64) {
+                if self.stopped {
+                    return;
+                }
+
+                if let Err(err) = self._validate() {
+                    godot_warn!("Animator error: {}", err);
+                    return;
+                }
+
+                self._step_limited(delta * self.time_scale as f64);
             }
 
-            fn _update_initial_values(&mut self) {
-                self.system.update_initial_values(
-                    $get_node_value(self.target.as_ref().unwrap()),
-                    $get_node_value(self.follower.as_ref().unwrap()),
-                    $system_inner_type_default,
-                );
+            /// Validates on the per-frame paths, allowing a new warning once the animator is valid again.
+            fn _validate_and_track(&mut self) -> Result<(), AnimatorError> {
+                let result = self._validate();
+                if result.is_ok() {
+                    self.has_warned = false;
+                }
+
+                result
             }
 
-            fn _update(&mut self, delta: f64) {
-                let input = $get_node_value(self.target.as_ref().unwrap());
-                let output = self.system.update(input, delta);
-                $set_node_value(self.follower.as_mut().unwrap(), output);
+            /// Warns only the first time the validation fails, instead of every frame.
+            fn _warn_once(&mut self, err: AnimatorError) {
+                if !self.has_warned {
+                    godot_warn!("Animator error: {}", err);
+                    self.has_warned = true;
+                }
             }
 
             fn _validate(&self) -> Result<(), AnimatorError> {
-                if self.target.is_none() {
+                if self.target_source == TargetSource::Node && self.target.is_none() {
                     return Err(AnimatorError::NodeNotSpecified("target"));
                 }
-                if self.follower.is_none() {
+                if self.target_source == TargetSource::Mouse && Self::_mouse_getter().is_none() {
+                    return Err(AnimatorError::MouseNotSupported);
+                }
+                if self.target_source == TargetSource::Mouse
+                    && Self::_mouse_in_physics()
+                    && self.interpolation_mode != InterpolationMode::Physics
+                {
+                    return Err(AnimatorError::MouseNeedsPhysics);
+                }
+                if self.follower.is_none() && self.followers.is_empty() {
                     return Err(AnimatorError::NodeNotSpecified("follower"));
                 }
 
@@ -145,27 +2077,58 @@ macro_rules! generate_animator {
                 &mut self,
                 notification: NodeNotification,
             ) -> Result<(), AnimatorError> {
-                if !self.active || (Engine::singleton().is_editor_hint() && !self.run_in_editor) {
+                if let NodeNotification::EnterTree | NodeNotification::Ready = notification {
+                    self.is_editor = Engine::singleton().is_editor_hint();
+                }
+                if let NodeNotification::Ready = notification {
+                    self._resolve_paths();
+                    self._connect_global_defaults();
+                }
+
+                if !self.active || (self.is_editor && !self.run_in_editor) {
                     return Ok(());
                 }
 
                 match (notification, self.interpolation_mode) {
+                    (NodeNotification::Process | NodeNotification::PhysicsProcess, _)
+                        if self.stopped => {}
+                    // The target is watched in the process of the interpolation mode only, as reading
+                    // it may query the physics space.
+                    (NodeNotification::Process, InterpolationMode::Process)
+                    | (NodeNotification::PhysicsProcess, InterpolationMode::Physics)
+                        if self.sleeping =>
+                    {
+                        self._validate_and_track()?;
+                        self._watch_target();
+                    }
+                    (NodeNotification::Process | NodeNotification::PhysicsProcess, _)
+                        if self.sleeping => {}
                     (NodeNotification::Process, InterpolationMode::Process) => {
-                        self._validate()?;
+                        self._validate_and_track()?;
 
                         let delta = self.base().get_process_delta_time();
-                        self._update(delta);
+                        self._advance(delta);
                     }
                     (NodeNotification::PhysicsProcess, InterpolationMode::Physics) => {
-                        self._validate()?;
+                        self._validate_and_track()?;
 
                         let delta = self.base().get_physics_process_delta_time();
-                        self._update(delta);
+                        self._advance(delta);
+                    }
+                    (NodeNotification::Process, InterpolationMode::Physics)
+                        if self.render_interpolated =>
+                    {
+                        self._validate_and_track()?;
+                        self._render_interpolated();
                     }
                     (NodeNotification::Ready, _) => {
-                        self._validate()?;
+                        self._validate_and_track()?;
                         self.base_mut().set_process(true);
                         self._update_initial_values();
+                        if self.snap_on_ready && self.mode == AnimationMode::Absolute {
+                            self._snap_on_ready();
+                        }
+                        self._warmup();
                     }
                     _ => {}
                 }
@@ -182,50 +2145,177 @@ macro_rules! generate_animator {
 
                 Self {
                     follower: None,
+                    followers: Array::new(),
                     target: None,
+                    follower_path: NodePath::default(),
+                    target_path: NodePath::default(),
+                    target_group: StringName::default(),
+                    target_source: TargetSource::Node,
+                    target_value: $system_inner_type_default,
+                    offset: <$system_inner_type>::no_offset(),
+                    mode: AnimationMode::Absolute,
+                    initial_velocity: <$system_type>::zero_derivative().into_velocity(),
                     active: true,
                     run_in_editor: false,
                     interpolation_mode: InterpolationMode::Physics,
+                    render_interpolated: false,
+                    activation_behavior: ActivationBehavior::SnapReference,
+                    snap_on_ready: true,
+                    initialized: false,
+                    stopped: false,
                     period,
+                    frequency: period,
                     damping,
                     response,
+                    preset: InterpolationPreset::Custom,
+                    parameters: None,
+                    use_global_defaults: false,
+                    global_defaults: None,
+                    global_values: None,
+                    clamp_unstable_params: false,
+                    integration_method: IntegrationMethod::SemiImplicitEuler,
+                    analytic_when_static: false,
+                    settle_threshold: 0.001,
+                    settled: false,
+                    auto_sleep: false,
+                    sleep_threshold: 0.001,
+                    sleeping: false,
+                    sleep_input: $system_inner_type_default,
+                    emit_value_changed: false,
+                    overshoot_threshold: 0.001,
+                    peak_overshoot: 0.0,
+                    overshoot_reported: false,
+                    overshoot_start: $system_inner_type_default,
+                    overshoot_target: $system_inner_type_default,
+                    deadzone: 0.0,
+                    teleport_threshold: 0.0,
+                    last_input: $system_inner_type_default,
+                    warmup_seconds: 0.0,
+                    time_scale: 1.0,
+                    ignore_engine_time_scale: false,
+                    max_delta: 0.0,
+                    split_large_delta: false,
+                    substeps: 1,
+                    is_editor: Engine::singleton().is_editor_hint(),
+                    has_warned: false,
+                    draw_debug: false,
+                    debug_line: DebugLine::new(),
+                    snap_follower_to_target: false,
                     system,
+                    follower_systems: Vec::new(),
+                    $( $global_flag: false, )?
+                    $( axis_mask: $axis_mask_default, )?
+                    $(
+                        enable_clamp: false,
+                        clamp_min: ($clamp_range).start,
+                        clamp_max: ($clamp_range).end,
+                    )?
+                    $(
+                        anisotropic: false,
+                        anisotropic_period: <$anisotropic_type>::ONE * period,
+                        anisotropic_damping: <$anisotropic_type>::ONE * damping,
+                        anisotropic_response: <$anisotropic_type>::ONE * response,
+                    )?
+                    $( $speed_limit: 0.0, )?
+                    $(
+                        use_velocity_source: false,
+                        velocity_source: Option::<Gd<$velocity_source_type>>::None,
+                    )?
+                    $( $( $field: $field_default, )* )?
                     base,
                 }
             }
 
+            fn get_configuration_warnings(&self) -> PackedStringArray {
+                let mut warnings = PackedStringArray::new();
+
+                if self.target_source == TargetSource::Node && self.target.is_none() {
+                    warnings.push(AnimatorError::NodeNotSpecified("target").to_string().into());
+                }
+                if self.target_source == TargetSource::Mouse && Self::_mouse_getter().is_none() {
+                    warnings.push(AnimatorError::MouseNotSupported.to_string().into());
+                }
+                if self.target_source == TargetSource::Mouse
+                    && Self::_mouse_in_physics()
+                    && self.interpolation_mode != InterpolationMode::Physics
+                {
+                    warnings.push(AnimatorError::MouseNeedsPhysics.to_string().into());
+                }
+                if self.follower.is_none() && self.followers.is_empty() {
+                    warnings.push(AnimatorError::NodeNotSpecified("follower").to_string().into());
+                }
+
+                warnings
+            }
+
             // The process and physics_process methods are used when the node has no script attached.
             // The on_notification method is used otherwise. Related to https://github.com/godot-rust/gdext/issues/111
 
             fn process(&mut self, delta: f64) {
-                if !self.active || (Engine::singleton().is_editor_hint() && !self.run_in_editor) {
+                if self.is_editor && self.draw_debug {
+                    self._draw_debug();
+                }
+
+                if !self.active
+                    || self.stopped
+                    || self.interpolation_mode == InterpolationMode::Manual
+                    || (self.is_editor && !self.run_in_editor)
+                {
                     return;
                 }
 
-                if let Err(err) = self._validate() {
-                    godot_warn!("Animator error: {}", err);
+                if let Err(err) = self._validate_and_track() {
+                    self._warn_once(err);
+                    return;
+                }
+
+                if self.sleeping {
+                    if self.interpolation_mode == InterpolationMode::Process {
+                        self._watch_target();
+                    }
                     return;
                 }
 
-                self._update(delta);
+                // The systems are only stepped in the process of the interpolation mode,
+                // otherwise a frame with a physics step would step them twice.
+                match self.interpolation_mode {
+                    InterpolationMode::Process => self._advance(delta),
+                    InterpolationMode::Physics if self.render_interpolated => {
+                        self._render_interpolated()
+                    }
+                    _ => {}
+                }
             }
 
             fn physics_process(&mut self, delta: f64) {
-                if !self.active || (Engine::singleton().is_editor_hint() && !self.run_in_editor) {
+                if !self.active
+                    || self.stopped
+                    || self.interpolation_mode == InterpolationMode::Manual
+                    || (self.is_editor && !self.run_in_editor)
+                {
                     return;
                 }
 
-                if let Err(err) = self._validate() {
-                    godot_warn!("Animator error: {}", err);
+                if let Err(err) = self._validate_and_track() {
+                    self._warn_once(err);
+                    return;
+                }
+
+                if self.sleeping {
+                    if self.interpolation_mode == InterpolationMode::Physics {
+                        self._watch_target();
+                    }
                     return;
                 }
 
-                self._update(delta);
+                if self.interpolation_mode == InterpolationMode::Physics {
+                    self._advance(delta);
+                }
             }
 
             fn on_notification(&mut self, notification: NodeNotification) {
                 if let Err(err) = self._proceed_notification(notification) {
-                    godot_warn!("Animator error: {}", err);
+                    self._warn_once(err);
                 }
             }
         }
@@ -236,61 +2326,671 @@ generate_animator!(
     AnimatorPosition3D,
     Node3D,
     SecondOrderSystemVector3,
+    Vector3,
     Vector3::ZERO,
     |node: &Gd<Node3D>| { node.get_position() },
-    |node: &mut Gd<Node3D>, value: Vector3| { node.set_position(value) }
+    |node: &mut Gd<Node3D>, value: Vector3| { node.set_position(value) },
+    // Global positions must be used when the follower and the target have different parents.
+    global {
+        use_global,
+        |node: &Gd<Node3D>| { node.get_global_position() },
+        |node: &mut Gd<Node3D>, value: Vector3| { node.set_global_position(value) },
+    },
+    axis_mask: Vector3 = Vector3::ONE,
+    clamp: Vector3 = Vector3::ZERO..Vector3::ONE,
+    anisotropic: Vector3,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    velocity: Node = "linear_velocity",
+    mouse = |animator: &AnimatorPosition3D, node: &Gd<Node3D>| {
+        let point = mouse_position_3d(node)?;
+
+        if animator.use_global {
+            return Some(point);
+        }
+
+        // The local position is relative to the parent.
+        Some(match node.get_parent_node_3d() {
+            Some(parent) => parent.to_local(point),
+            None => point,
+        })
+    },
+    mouse_in_physics: true
+);
+
+generate_animator!(
+    AnimatorGlobalPosition3D,
+    Node3D,
+    SecondOrderSystemVector3,
+    Vector3,
+    Vector3::ZERO,
+    |node: &Gd<Node3D>| { node.get_global_position() },
+    |node: &mut Gd<Node3D>, value: Vector3| { node.set_global_position(value) },
+    axis_mask: Vector3 = Vector3::ONE,
+    clamp: Vector3 = Vector3::ZERO..Vector3::ONE,
+    anisotropic: Vector3,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    velocity: Node = "linear_velocity",
+    mouse = |_animator: &AnimatorGlobalPosition3D, node: &Gd<Node3D>| {
+        mouse_position_3d(node)
+    },
+    mouse_in_physics: true
+);
+
+// Matches the world position only. The global transform is written back with the basis
+// it was read with, so the follower keeps its own rotation and scale.
+generate_animator!(
+    AnimatorGlobalOrigin3D,
+    Node3D,
+    SecondOrderSystemVector3,
+    Vector3,
+    Vector3::ZERO,
+    |node: &Gd<Node3D>| { node.get_global_transform().origin },
+    |node: &mut Gd<Node3D>, value: Vector3| {
+        let mut transform = node.get_global_transform();
+        transform.origin = value;
+        node.set_global_transform(transform)
+    },
+    axis_mask: Vector3 = Vector3::ONE,
+    clamp: Vector3 = Vector3::ZERO..Vector3::ONE,
+    anisotropic: Vector3,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    velocity: Node = "linear_velocity",
+    mouse = |_animator: &AnimatorGlobalOrigin3D, node: &Gd<Node3D>| {
+        mouse_position_3d(node)
+    },
+    mouse_in_physics: true
 );
 
 generate_animator!(
     AnimatorRotation3D,
     Node3D,
     SecondOrderSystemQuaternion,
+    Quaternion,
     Quaternion::default(),
     |node: &Gd<Node3D>| { node.get_quaternion() },
-    |node: &mut Gd<Node3D>, value: Quaternion| { node.set_quaternion(value) }
+    |node: &mut Gd<Node3D>, value: Quaternion| { node.set_quaternion(value) },
+    speed_limit {
+        max_angular_speed,
+        set_max_angular_speed,
+    },
+    velocity: Node = "angular_velocity"
+);
+
+// Euler angles in degrees are easier to tune, each of them takes the shortest way around.
+generate_animator!(
+    AnimatorEuler3D,
+    Node3D,
+    SecondOrderSystemEulerDegrees,
+    Vector3,
+    Vector3::ZERO,
+    |node: &Gd<Node3D>| { node.get_rotation_degrees() },
+    |node: &mut Gd<Node3D>, value: Vector3| { node.set_rotation_degrees(value) },
+    axis_mask: Vector3 = Vector3::ONE
 );
 
 generate_animator!(
     AnimatorScale3D,
     Node3D,
     SecondOrderSystemVector3,
+    Vector3,
     Vector3::ZERO,
     |node: &Gd<Node3D>| { node.get_scale() },
-    |node: &mut Gd<Node3D>, value: Vector3| { node.set_scale(value) }
+    |node: &mut Gd<Node3D>, value: Vector3| { node.set_scale(value) },
+    axis_mask: Vector3 = Vector3::ONE,
+    clamp: Vector3 = Vector3::ZERO..Vector3::ONE,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
 );
 
 generate_animator!(
     AnimatorPosition2D,
     Node2D,
     SecondOrderSystemVector2,
+    Vector2,
     Vector2::ZERO,
     |node: &Gd<Node2D>| { node.get_position() },
-    |node: &mut Gd<Node2D>, value: Vector2| { node.set_position(value) }
+    |node: &mut Gd<Node2D>, value: Vector2| { node.set_position(value) },
+    // Global positions must be used when the follower and the target have different parents.
+    global {
+        use_global,
+        |node: &Gd<Node2D>| { node.get_global_position() },
+        |node: &mut Gd<Node2D>, value: Vector2| { node.set_global_position(value) },
+    },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    anisotropic: Vector2,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    velocity: Node = "linear_velocity",
+    mouse = |animator: &AnimatorPosition2D, node: &Gd<Node2D>| {
+        if animator.use_global {
+            return Some(node.get_global_mouse_position());
+        }
+
+        // The local position is relative to the parent, so the mouse is moved out of the node space.
+        Some(node.get_transform() * node.get_local_mouse_position())
+    }
+);
+
+generate_animator!(
+    AnimatorGlobalPosition2D,
+    Node2D,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Node2D>| { node.get_global_position() },
+    |node: &mut Gd<Node2D>, value: Vector2| { node.set_global_position(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    anisotropic: Vector2,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    velocity: Node = "linear_velocity",
+    mouse = |_animator: &AnimatorGlobalPosition2D, node: &Gd<Node2D>| {
+        Some(node.get_global_mouse_position())
+    }
 );
 
 generate_animator!(
     AnimatorRotation2D,
     Node2D,
-    SecondOrderSystemFloat,
+    SecondOrderSystemAngle,
+    f32,
     0.0,
     |node: &Gd<Node2D>| { node.get_rotation() },
-    |node: &mut Gd<Node2D>, value: f32| { node.set_rotation(value) }
+    |node: &mut Gd<Node2D>, value: f32| { node.set_rotation(value) },
+    velocity: Node = "angular_velocity"
 );
 
 generate_animator!(
     AnimatorScale2D,
     Node2D,
     SecondOrderSystemVector2,
+    Vector2,
     Vector2::ZERO,
     |node: &Gd<Node2D>| { node.get_scale() },
-    |node: &mut Gd<Node2D>, value: Vector2| { node.set_scale(value) }
+    |node: &mut Gd<Node2D>, value: Vector2| { node.set_scale(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
 );
 
 generate_animator!(
     AnimatorSkew2D,
     Node2D,
-    SecondOrderSystemFloat,
+    SecondOrderSystemAngle,
+    f32,
     0.0,
     |node: &Gd<Node2D>| { node.get_skew() },
     |node: &mut Gd<Node2D>, value: f32| { node.set_skew(value) }
 );
+
+generate_animator!(
+    AnimatorColorModulate,
+    CanvasItem,
+    SecondOrderSystemColor,
+    Color,
+    Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+    |node: &Gd<CanvasItem>| { node.get_modulate() },
+    |node: &mut Gd<CanvasItem>, value: Color| { node.set_modulate(value) },
+    fields {
+        #[export]
+        clamp_output: bool = false,
+        #[export]
+        color_space: ColorSpace = ColorSpace::Rgb,
+    },
+    pre_process = |animator: &mut AnimatorColorModulate, value: Color| {
+        sync_color_space(
+            &mut animator.system,
+            &mut animator.follower_systems,
+            animator.color_space,
+        );
+        value
+    },
+    post_process = |animator: &mut AnimatorColorModulate, _input: Color, value: Color| {
+        if animator.clamp_output {
+            clamp_color(value, 0.0, 1.0)
+        } else {
+            value
+        }
+    }
+);
+
+generate_animator!(
+    AnimatorSelfModulate,
+    CanvasItem,
+    SecondOrderSystemColor,
+    Color,
+    Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+    |node: &Gd<CanvasItem>| { node.get_self_modulate() },
+    |node: &mut Gd<CanvasItem>, value: Color| { node.set_self_modulate(value) },
+    fields {
+        #[export]
+        clamp_output: bool = false,
+        #[export]
+        color_space: ColorSpace = ColorSpace::Rgb,
+    },
+    pre_process = |animator: &mut AnimatorSelfModulate, value: Color| {
+        sync_color_space(
+            &mut animator.system,
+            &mut animator.follower_systems,
+            animator.color_space,
+        );
+        value
+    },
+    post_process = |animator: &mut AnimatorSelfModulate, _input: Color, value: Color| {
+        if animator.clamp_output {
+            clamp_color(value, 0.0, 1.0)
+        } else {
+            value
+        }
+    }
+);
+
+// Only the alpha is smoothed, so a fade doesn't shift the color.
+generate_animator!(
+    AnimatorModulateAlpha,
+    CanvasItem,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<CanvasItem>| { node.get_modulate().a },
+    |node: &mut Gd<CanvasItem>, value: f32| {
+        let mut modulate = node.get_modulate();
+        modulate.a = value;
+        node.set_modulate(modulate)
+    },
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorModulateAlpha, _input: f32, value: f32| { value.clamp(0.0, 1.0) }
+);
+
+generate_animator!(
+    AnimatorCamera3DFov,
+    Camera3D,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<Camera3D>| { node.get_fov() },
+    |node: &mut Gd<Camera3D>, value: f32| { node.set_fov(value) },
+    clamp: f32 = CAMERA_3D_FOV_RANGE.0..CAMERA_3D_FOV_RANGE.1,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    fields {
+        fov_out_of_range_warned: bool = false,
+    },
+    post_process = |animator: &mut AnimatorCamera3DFov, input: f32, value: f32| {
+        let (min, max) = CAMERA_3D_FOV_RANGE;
+        let out_of_range = !(min..=max).contains(&input);
+
+        if out_of_range && !animator.fov_out_of_range_warned {
+            godot_warn!(
+                "Animator warning: The target fov {} is out of range [{}, {}].",
+                input,
+                min,
+                max
+            );
+        }
+        animator.fov_out_of_range_warned = out_of_range;
+
+        value.clamp(min, max)
+    }
+);
+
+generate_animator!(
+    AnimatorCamera2DZoom,
+    Camera2D,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Camera2D>| { node.get_zoom() },
+    |node: &mut Gd<Camera2D>, value: Vector2| { node.set_zoom(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::new(0.5, 0.5)..Vector2::new(2.0, 2.0),
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    fields {
+        #[export]
+        min_zoom: Vector2 = Vector2::new(0.01, 0.01),
+    },
+    post_process = |animator: &mut AnimatorCamera2DZoom, _input: Vector2, value: Vector2| {
+        Vector2::new(
+            value.x.max(animator.min_zoom.x),
+            value.y.max(animator.min_zoom.y),
+        )
+    }
+);
+
+generate_animator!(
+    AnimatorCamera2DOffset,
+    Camera2D,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Camera2D>| { node.get_offset() },
+    |node: &mut Gd<Camera2D>, value: Vector2| { node.set_offset(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
+);
+
+generate_animator!(
+    AnimatorLight3DEnergy,
+    Light3D,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<Light3D>| { node.get_param(light_3d::Param::ENERGY) },
+    |node: &mut Gd<Light3D>, value: f32| { node.set_param(light_3d::Param::ENERGY, value) },
+    clamp: f32 = 0.0..1.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorLight3DEnergy, _input: f32, value: f32| { value.max(0.0) }
+);
+
+generate_animator!(
+    AnimatorAudioVolumeDb,
+    AudioStreamPlayer,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<AudioStreamPlayer>| { node.get_volume_db() },
+    |node: &mut Gd<AudioStreamPlayer>, value: f32| { node.set_volume_db(value) },
+    clamp: f32 = -80.0..0.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    fields {
+        #[export]
+        silence_floor_db: f32 = -80.0,
+    },
+    pre_process = |animator: &mut AnimatorAudioVolumeDb, value: f32| {
+        // -inf dB is a valid volume, but interpolating toward it breaks the derivative.
+        value.max(animator.silence_floor_db)
+    },
+    post_process = |animator: &mut AnimatorAudioVolumeDb, _input: f32, value: f32| {
+        value.max(animator.silence_floor_db)
+    }
+);
+
+generate_animator!(
+    AnimatorAudioPitchScale,
+    AudioStreamPlayer,
+    SecondOrderSystemFloat,
+    f32,
+    1.0,
+    |node: &Gd<AudioStreamPlayer>| { node.get_pitch_scale() },
+    |node: &mut Gd<AudioStreamPlayer>, value: f32| { node.set_pitch_scale(value) },
+    clamp: f32 = MIN_PITCH_SCALE..4.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorAudioPitchScale, _input: f32, value: f32| { value.max(MIN_PITCH_SCALE) }
+);
+
+generate_animator!(
+    AnimatorAudioPitchScale2D,
+    AudioStreamPlayer2D,
+    SecondOrderSystemFloat,
+    f32,
+    1.0,
+    |node: &Gd<AudioStreamPlayer2D>| { node.get_pitch_scale() },
+    |node: &mut Gd<AudioStreamPlayer2D>, value: f32| { node.set_pitch_scale(value) },
+    clamp: f32 = MIN_PITCH_SCALE..4.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorAudioPitchScale2D, _input: f32, value: f32| { value.max(MIN_PITCH_SCALE) }
+);
+
+generate_animator!(
+    AnimatorAudioPitchScale3D,
+    AudioStreamPlayer3D,
+    SecondOrderSystemFloat,
+    f32,
+    1.0,
+    |node: &Gd<AudioStreamPlayer3D>| { node.get_pitch_scale() },
+    |node: &mut Gd<AudioStreamPlayer3D>, value: f32| { node.set_pitch_scale(value) },
+    clamp: f32 = MIN_PITCH_SCALE..4.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorAudioPitchScale3D, _input: f32, value: f32| { value.max(MIN_PITCH_SCALE) }
+);
+
+generate_animator!(
+    AnimatorControlPosition,
+    Control,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Control>| { node.get_position() },
+    |node: &mut Gd<Control>, value: Vector2| { node.set_position(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    anisotropic: Vector2,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    mouse = |_animator: &AnimatorControlPosition, node: &Gd<Control>| {
+        Some(node.get_transform() * node.get_local_mouse_position())
+    }
+);
+
+generate_animator!(
+    AnimatorControlSize,
+    Control,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Control>| { node.get_size() },
+    |node: &mut Gd<Control>, value: Vector2| {
+        if (node.get_size() - value).length() > CONTROL_SIZE_EPSILON {
+            node.set_size(value)
+        }
+    },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
+);
+
+generate_animator!(
+    AnimatorControlPivotOffset,
+    Control,
+    SecondOrderSystemVector2,
+    Vector2,
+    Vector2::ZERO,
+    |node: &Gd<Control>| { node.get_pivot_offset() },
+    |node: &mut Gd<Control>, value: Vector2| { node.set_pivot_offset(value) },
+    axis_mask: Vector2 = Vector2::ONE,
+    clamp: Vector2 = Vector2::ZERO..Vector2::ONE,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
+);
+
+generate_animator!(
+    AnimatorControlRotation,
+    Control,
+    SecondOrderSystemAngle,
+    f32,
+    0.0,
+    |node: &Gd<Control>| { node.get_rotation() },
+    |node: &mut Gd<Control>, value: f32| { node.set_rotation(value) }
+);
+
+generate_animator!(
+    AnimatorRange,
+    Range,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<Range>| { node.get_value() as f32 },
+    |node: &mut Gd<Range>, value: f32| {
+        // Clamped even when the range allows greater or lesser values, so overshoot stays within the bar.
+        let value = (value as f64).max(node.get_min()).min(node.get_max());
+        node.set_value(value)
+    },
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    }
+);
+
+generate_animator!(
+    AnimatorSpringArm3D,
+    SpringArm3D,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<SpringArm3D>| { node.get_length() },
+    |node: &mut Gd<SpringArm3D>, value: f32| { node.set_length(value) },
+    clamp: f32 = 0.0..10.0,
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process =
+        |_animator: &mut AnimatorSpringArm3D, _input: f32, value: f32| { value.max(0.0) }
+);
+
+// The quaternion system always takes the shortest arc, so the sun never spins backward across midnight.
+generate_animator!(
+    AnimatorDirectionalLightRotation,
+    DirectionalLight3D,
+    SecondOrderSystemQuaternion,
+    Quaternion,
+    Quaternion::default(),
+    |node: &Gd<DirectionalLight3D>| { node.get_quaternion() },
+    |node: &mut Gd<DirectionalLight3D>, value: Quaternion| { node.set_quaternion(value) },
+    speed_limit {
+        max_angular_speed,
+        set_max_angular_speed,
+    }
+);
+
+generate_animator!(
+    AnimatorParticlesAmountRatio3D,
+    GpuParticles3D,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<GpuParticles3D>| { node.get_amount_ratio() },
+    |node: &mut Gd<GpuParticles3D>, value: f32| { node.set_amount_ratio(value) },
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process = |_animator: &mut AnimatorParticlesAmountRatio3D, _input: f32, value: f32| {
+        value.clamp(0.0, 1.0)
+    }
+);
+
+generate_animator!(
+    AnimatorParticlesAmountRatio2D,
+    GpuParticles2D,
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    |node: &Gd<GpuParticles2D>| { node.get_amount_ratio() },
+    |node: &mut Gd<GpuParticles2D>, value: f32| { node.set_amount_ratio(value) },
+    speed_limit {
+        max_speed,
+        set_max_speed,
+    },
+    post_process = |_animator: &mut AnimatorParticlesAmountRatio2D, _input: f32, value: f32| {
+        value.clamp(0.0, 1.0)
+    }
+);
+
+generate_animator!(
+    AnimatorCanvasModulate,
+    CanvasModulate,
+    SecondOrderSystemColor,
+    Color,
+    Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+    |node: &Gd<CanvasModulate>| { node.get_color() },
+    |node: &mut Gd<CanvasModulate>, value: Color| { node.set_color(value) },
+    fields {
+        #[export]
+        color_space: ColorSpace = ColorSpace::Rgb,
+    },
+    pre_process = |animator: &mut AnimatorCanvasModulate, value: Color| {
+        sync_color_space(
+            &mut animator.system,
+            &mut animator.follower_systems,
+            animator.color_space,
+        );
+        value
+    },
+    // The whole scene is tinted, so an overshoot below zero would turn into negative light.
+    post_process = |_animator: &mut AnimatorCanvasModulate, _input: Color, value: Color| {
+        clamp_color(value, 0.0, f32::INFINITY)
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn additive_snap_keeps_the_baseline() {
+        // The follower is at 10 with an offset of 1 applied, so another driver has put it at 9.
+        let value = Vector3::new(10.0, 0.0, 0.0);
+        let offset = Vector3::new(1.0, 0.0, 0.0);
+        let baseline = Some(value.remove_offset(offset));
+
+        let snapped = AnimatorPosition3D::_apply_baseline(baseline, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(snapped, Vector3::new(11.0, 0.0, 0.0));
+
+        let snapped = AnimatorPosition3D::_apply_baseline(baseline, Vector3::no_offset());
+        assert_eq!(snapped, Vector3::new(9.0, 0.0, 0.0));
+
+        // The absolute mode has no baseline, the target is the value itself.
+        let snapped = AnimatorPosition3D::_apply_baseline(None, Vector3::new(2.0, 0.0, 0.0));
+        assert_eq!(snapped, Vector3::new(2.0, 0.0, 0.0));
+    }
+}
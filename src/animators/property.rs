@@ -0,0 +1,944 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine, WorldEnvironment},
+    prelude::*,
+};
+
+use super::{warn_on_period, warn_on_recovery, AnimatorError, InterpolationMode};
+use crate::second_order_systems::*;
+
+/// Animates any numeric property of the follower toward a numeric property of the target.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorFloatProperty {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_follower_property)]
+    follower_property: StringName,
+    #[export]
+    #[var(get, set = set_target_property)]
+    target_property: StringName,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+    // The fastest the follower may change, per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_speed)]
+    max_speed: f32,
+
+    system: SecondOrderSystemFloat,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorFloatProperty {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_follower_property(&mut self, value: StringName) {
+        self.follower_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target_property(&mut self, value: StringName) {
+        self.target_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_speed(&mut self, value: f32) {
+        self.max_speed = value.max(0.0);
+        self.system.update_max_speed(self.max_speed);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let previous = get_float_property(self.target.as_ref().unwrap(), &self.target_property);
+        let current = get_float_property(self.follower.as_ref().unwrap(), &self.follower_property);
+
+        self.system
+            .update_initial_values(previous.unwrap(), current.unwrap(), 0.0);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let input = get_float_property(self.target.as_ref().unwrap(), &self.target_property);
+        let output = self.system.update(input.unwrap(), delta);
+        warn_on_recovery(self.system.take_recovered());
+
+        self.follower
+            .as_mut()
+            .unwrap()
+            .set(self.follower_property.clone(), output.to_variant());
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        let Some(target) = self.target.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        };
+        let Some(follower) = self.follower.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        };
+
+        if get_float_property(target, &self.target_property).is_none() {
+            return Err(AnimatorError::PropertyNotFound(
+                "target",
+                self.target_property.to_string(),
+            ));
+        }
+        if get_float_property(follower, &self.follower_property).is_none() {
+            return Err(AnimatorError::PropertyNotFound(
+                "follower",
+                self.follower_property.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorFloatProperty, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        follower_property: StringName::default(),
+        target_property: StringName::default(),
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        max_speed: 0.0,
+        system: SecondOrderSystemFloat::new(period, damping, response),
+        base,
+    }
+});
+
+/// Animates a numeric property of the Environment of the follower, e.g. fog_density, toward
+/// a value set from the inspector or script. Only one WorldEnvironment is active in a world,
+/// so the target is a value rather than another node. The environment is fetched every frame,
+/// so swapping it at runtime is fine.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorEnvironmentFloat {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<WorldEnvironment>>,
+    #[export]
+    #[var(get, set = set_property_name)]
+    property_name: StringName,
+    #[export]
+    target_value: f32,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+    // The fastest the follower may change, per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_speed)]
+    max_speed: f32,
+
+    system: SecondOrderSystemFloat,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorEnvironmentFloat {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<WorldEnvironment>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_property_name(&mut self, value: StringName) {
+        self.property_name = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_speed(&mut self, value: f32) {
+        self.max_speed = value.max(0.0);
+        self.system.update_max_speed(self.max_speed);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let current = get_environment_float(self.follower.as_ref().unwrap(), &self.property_name);
+
+        self.system
+            .update_initial_values(self.target_value, current.unwrap(), 0.0);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        // Densities and energies are meaningless below zero.
+        let output = self.system.update(self.target_value, delta).max(0.0);
+        warn_on_recovery(self.system.take_recovered());
+
+        if let Some(mut environment) = self.follower.as_ref().unwrap().get_environment() {
+            environment.set(self.property_name.clone(), output.to_variant());
+        }
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        let Some(follower) = self.follower.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        };
+
+        if follower.get_environment().is_none() {
+            return Err(AnimatorError::ResourceNotSpecified("environment"));
+        }
+        if get_environment_float(follower, &self.property_name).is_none() {
+            return Err(AnimatorError::PropertyNotFound(
+                "follower",
+                self.property_name.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorEnvironmentFloat, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        property_name: StringName::default(),
+        target_value: 0.0,
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        max_speed: 0.0,
+        system: SecondOrderSystemFloat::new(period, damping, response),
+        base,
+    }
+});
+
+/// Reads a property as a float. Returns None if there is no such property or it is not numeric.
+#[inline]
+fn get_float_property(node: &Gd<Node>, property: &StringName) -> Option<f32> {
+    variant_to_float(node.get(property.clone()))
+}
+
+/// Reads a property of the Environment as a float. Returns None if there is no environment,
+/// no such property or it is not numeric.
+#[inline]
+fn get_environment_float(node: &Gd<WorldEnvironment>, property: &StringName) -> Option<f32> {
+    variant_to_float(node.get_environment()?.get(property.clone()))
+}
+
+#[inline]
+fn variant_to_float(value: Variant) -> Option<f32> {
+    match value.get_type() {
+        VariantType::Float => value.try_to::<f32>().ok(),
+        VariantType::Int => value.try_to::<i64>().ok().map(|value| value as f32),
+        _ => None,
+    }
+}
+
+/// Animates a Rect2 property of the follower toward a Rect2 property of the target,
+/// e.g. camera limits or selection boxes. Position and size are stepped together.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorRect2 {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_follower_property)]
+    follower_property: StringName,
+    #[export]
+    #[var(get, set = set_target_property)]
+    target_property: StringName,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+
+    system: SecondOrderSystemRect2,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorRect2 {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_follower_property(&mut self, value: StringName) {
+        self.follower_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target_property(&mut self, value: StringName) {
+        self.target_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let previous = get_rect2_property(self.target.as_ref().unwrap(), &self.target_property);
+        let current = get_rect2_property(self.follower.as_ref().unwrap(), &self.follower_property);
+
+        self.system
+            .update_initial_values(previous.unwrap(), current.unwrap());
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let input = get_rect2_property(self.target.as_ref().unwrap(), &self.target_property);
+        let output = self.system.update(input.unwrap(), delta);
+        warn_on_recovery(self.system.take_recovered());
+
+        self.follower
+            .as_mut()
+            .unwrap()
+            .set(self.follower_property.clone(), output.to_variant());
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        let Some(target) = self.target.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        };
+        let Some(follower) = self.follower.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        };
+
+        if get_rect2_property(target, &self.target_property).is_none() {
+            return Err(AnimatorError::PropertyNotFound(
+                "target",
+                self.target_property.to_string(),
+            ));
+        }
+        if get_rect2_property(follower, &self.follower_property).is_none() {
+            return Err(AnimatorError::PropertyNotFound(
+                "follower",
+                self.follower_property.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorRect2, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        follower_property: StringName::default(),
+        target_property: StringName::default(),
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        system: SecondOrderSystemRect2::new(period, damping, response),
+        base,
+    }
+});
+
+/// Reads a property as a Rect2. Returns None if there is no such property or it is not a Rect2.
+#[inline]
+fn get_rect2_property(node: &Gd<Node>, property: &StringName) -> Option<Rect2> {
+    node.get(property.clone()).try_to::<Rect2>().ok()
+}
+
+macro_rules! generate_variant_system {
+    // This macro generates a system dispatching to the typed system matching a Variant type.
+    // Parameters: the Variant types with their system types and value types.
+    ($( $variant_type:ident => $system_type:ty, $value_type:ty ),* $(,)?) => {
+        /// A system picked at runtime by the type of the animated property.
+        enum VariantSystem {
+            $( $variant_type($system_type), )*
+        }
+
+        impl VariantSystem {
+            /// A system for the values of the type, or None if the type can't be animated.
+            fn for_type(variant_type: VariantType, period: f32, damping: f32, response: f32) -> Option<Self> {
+                match variant_type {
+                    $(
+                        VariantType::$variant_type => {
+                            Some(Self::$variant_type(<$system_type>::new(period, damping, response)))
+                        }
+                    )*
+                    _ => None,
+                }
+            }
+
+            fn supports(variant_type: VariantType) -> bool {
+                matches!(variant_type, $( VariantType::$variant_type )|*)
+            }
+
+            fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+                match self {
+                    $( Self::$variant_type(system) => system.update_params(period, damping, response), )*
+                }
+            }
+
+            /// Values not of the type of the system are ignored, keeping the previous state.
+            fn update_initial_values(&mut self, previous: &Variant, current: &Variant) {
+                match self {
+                    $(
+                        Self::$variant_type(system) => {
+                            if let (Ok(previous), Ok(current)) =
+                                (previous.try_to::<$value_type>(), current.try_to::<$value_type>())
+                            {
+                                system.update_initial_values(
+                                    previous,
+                                    current,
+                                    <$system_type>::zero_derivative(),
+                                );
+                            }
+                        }
+                    )*
+                }
+            }
+
+            /// Returns None if the input is not of the type of the system.
+            fn update(&mut self, input: &Variant, delta: f64) -> Option<Variant> {
+                match self {
+                    $(
+                        Self::$variant_type(system) => {
+                            let input = input.try_to::<$value_type>().ok()?;
+                            let output = system.update(input, delta);
+                            warn_on_recovery(system.take_recovered());
+                            Some(output.to_variant())
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+generate_variant_system!(
+    Float => SecondOrderSystemFloat, f32,
+    Vector2 => SecondOrderSystemVector2, Vector2,
+    Vector3 => SecondOrderSystemVector3, Vector3,
+    Color => SecondOrderSystemColor, Color,
+    Quaternion => SecondOrderSystemQuaternion, Quaternion,
+);
+
+/// Animates a property of any supported type, which is only known at runtime, e.g. a property
+/// of a script or a plugin. The system is picked by the type of the follower property on activation.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorVariantProperty {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node>>,
+    #[export]
+    #[var(get, set = set_follower_property)]
+    follower_property: StringName,
+    #[export]
+    #[var(get, set = set_target_property)]
+    target_property: StringName,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+
+    // Created on activation, once the type of the property is known.
+    system: Option<VariantSystem>,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorVariantProperty {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_follower_property(&mut self, value: StringName) {
+        self.follower_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target_property(&mut self, value: StringName) {
+        self.target_property = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self._update_parameters();
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self._update_parameters();
+    }
+
+    fn _update_parameters(&mut self) {
+        if let Some(system) = self.system.as_mut() {
+            system.update_params(self.period, self.damping, self.response);
+        }
+    }
+
+    fn _update_initial_values(&mut self) {
+        let previous = self
+            .target
+            .as_ref()
+            .unwrap()
+            .get(self.target_property.clone());
+        let current = self
+            .follower
+            .as_ref()
+            .unwrap()
+            .get(self.follower_property.clone());
+
+        // Picked again every time, as the property may have changed its type since.
+        // An unsupported type is reported by the validation, nothing is animated meanwhile.
+        let Some(mut system) =
+            VariantSystem::for_type(current.get_type(), self.period, self.damping, self.response)
+        else {
+            self.system = None;
+            return;
+        };
+        system.update_initial_values(&previous, &current);
+
+        self.system = Some(system);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let input = self
+            .target
+            .as_ref()
+            .unwrap()
+            .get(self.target_property.clone());
+
+        // There is no system of the type yet, when the animator has not been activated
+        // or the properties have changed their type since.
+        let Some(output) = self
+            .system
+            .as_mut()
+            .and_then(|system| system.update(&input, delta))
+        else {
+            self._update_initial_values();
+            return;
+        };
+
+        self.follower
+            .as_mut()
+            .unwrap()
+            .set(self.follower_property.clone(), output);
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        let Some(target) = self.target.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        };
+        let Some(follower) = self.follower.as_ref() else {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        };
+
+        let target_value = target.get(self.target_property.clone());
+        let follower_value = follower.get(self.follower_property.clone());
+
+        if target_value.is_nil() {
+            return Err(AnimatorError::PropertyNotFound(
+                "target",
+                self.target_property.to_string(),
+            ));
+        }
+        if follower_value.is_nil() {
+            return Err(AnimatorError::PropertyNotFound(
+                "follower",
+                self.follower_property.to_string(),
+            ));
+        }
+        if !VariantSystem::supports(follower_value.get_type()) {
+            return Err(AnimatorError::UnsupportedPropertyType(
+                "follower",
+                self.follower_property.to_string(),
+                follower_value.get_type(),
+            ));
+        }
+        if target_value.get_type() != follower_value.get_type() {
+            return Err(AnimatorError::PropertyTypesDiffer(
+                self.follower_property.to_string(),
+                self.target_property.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorVariantProperty, |base| {
+    Self {
+        follower: None,
+        target: None,
+        follower_property: StringName::default(),
+        target_property: StringName::default(),
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period: 1.0,
+        damping: 0.5,
+        response: 2.0,
+        system: None,
+        base,
+    }
+});
@@ -0,0 +1,223 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine, ShaderMaterial},
+    prelude::*,
+};
+
+use super::{warn_on_period, warn_on_recovery, AnimatorError, InterpolationMode};
+use crate::second_order_systems::*;
+
+macro_rules! generate_shader_parameter_animator {
+    // This macro generates animator classes for shader uniforms of different types.
+    // The follower is a uniform of the material, the target is a value set from the inspector or script.
+    // Parameters:
+    // $node_name: The name of the generated animator class.
+    // $system_type: The type of the second-order system used for interpolation.
+    // $value_type: The type of the uniform.
+    // $system_inner_type_default: The default value for the system's inner type (e.g., Vector4::ZERO).
+    (
+        $node_name:ident,
+        $system_type:ty,
+        $value_type:ty,
+        $system_inner_type_default:expr
+    ) => {
+        #[derive(GodotClass)]
+        #[class(tool, base=Node)]
+        struct $node_name {
+            #[export]
+            #[var(get, set = set_material)]
+            material: Option<Gd<ShaderMaterial>>,
+            #[export]
+            #[var(get, set = set_parameter_name)]
+            parameter_name: StringName,
+            #[export]
+            target_value: $value_type,
+
+            #[export]
+            #[var(get, set = set_active)]
+            active: bool,
+            #[export]
+            #[var(get, set = set_run_in_editor)]
+            run_in_editor: bool,
+            #[export]
+            #[var(get, set = set_interpolation_mode)]
+            interpolation_mode: InterpolationMode,
+            // Can't change while the game runs, so it's not queried from the engine every frame.
+            is_editor: bool,
+            has_warned: bool,
+
+            #[export]
+            #[var(get, set = set_period)]
+            period: f32,
+            #[export]
+            #[var(get, set = set_damping)]
+            damping: f32,
+            #[export]
+            #[var(get, set = set_response)]
+            response: f32,
+
+            system: $system_type,
+            last_output: $value_type,
+
+            base: Base<Node>,
+        }
+
+        #[godot_api]
+        impl $node_name {
+            #[func]
+            fn set_material(&mut self, value: Option<Gd<ShaderMaterial>>) {
+                self.material = value;
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_parameter_name(&mut self, value: StringName) {
+                self.parameter_name = value;
+                self.base_mut().update_configuration_warnings();
+            }
+            #[func]
+            fn set_active(&mut self, value: bool) {
+                if self.active != value {
+                    self.active = value;
+                }
+
+                if self.active && self._validate().is_ok() {
+                    self._update_initial_values();
+                }
+            }
+            #[func]
+            fn set_run_in_editor(&mut self, value: bool) {
+                if self.run_in_editor != value {
+                    self.run_in_editor = value;
+                }
+
+                if self.active && self._validate().is_ok() {
+                    self._update_initial_values();
+                }
+            }
+            #[func]
+            fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+                if self.interpolation_mode != value {
+                    self.interpolation_mode = value;
+                }
+            }
+            #[func]
+            fn set_period(&mut self, value: f32) {
+                self.period = value;
+                warn_on_period(value);
+                self.system.update_period(self.period);
+            }
+            #[func]
+            fn set_damping(&mut self, value: f32) {
+                self.damping = value;
+                self.system.update_damping(self.damping);
+            }
+            #[func]
+            fn set_response(&mut self, value: f32) {
+                self.response = value;
+                self.system.update_response(self.response);
+            }
+            /// Sets the three parameters at once, recomputing the system a single time.
+            #[func]
+            fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+                self.period = period;
+                warn_on_period(period);
+                self.damping = damping;
+                self.response = response;
+                self.system.update_params(period, damping, response);
+            }
+
+            fn _read_parameter(&self) -> Option<$value_type> {
+                self.material
+                    .as_ref()
+                    .unwrap()
+                    .get_shader_parameter(self.parameter_name.clone())
+                    .try_to::<$value_type>()
+                    .ok()
+            }
+
+            fn _update_initial_values(&mut self) {
+                let current = self._read_parameter().unwrap_or(self.target_value);
+
+                self.system.update_initial_values(
+                    self.target_value,
+                    current,
+                    $system_inner_type_default,
+                );
+                self.last_output = current;
+            }
+
+            fn _update(&mut self, delta: f64) {
+                // The uniform can be changed by someone else, the smoothing continues from that value then.
+                if let Some(current) = self._read_parameter() {
+                    if current != self.last_output {
+                        self.system.update_initial_values(
+                            self.target_value,
+                            current,
+                            $system_inner_type_default,
+                        );
+                    }
+                }
+
+                let output = self.system.update(self.target_value, delta);
+                warn_on_recovery(self.system.take_recovered());
+                self.material
+                    .as_mut()
+                    .unwrap()
+                    .set_shader_parameter(self.parameter_name.clone(), output.to_variant());
+                self.last_output = output;
+            }
+
+            /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+            #[func]
+            fn advance(&mut self, delta: f64) {
+                if let Err(err) = self._validate() {
+                    godot_warn!("Animator error: {}", err);
+                    return;
+                }
+
+                self._update(delta);
+            }
+
+            fn _validate(&self) -> Result<(), AnimatorError> {
+                if self.material.is_none() {
+                    return Err(AnimatorError::ResourceNotSpecified("material"));
+                }
+                if self.parameter_name == StringName::default() {
+                    return Err(AnimatorError::PropertyNotSpecified("parameter_name"));
+                }
+
+                Ok(())
+            }
+        }
+
+        impl_animator_callbacks!($node_name, |base| {
+            let (period, damping, response) = (1.0, 0.5, 2.0);
+            let system = <$system_type>::new(period, damping, response);
+
+            Self {
+                material: None,
+                parameter_name: StringName::default(),
+                target_value: $system_inner_type_default,
+                active: true,
+                run_in_editor: false,
+                interpolation_mode: InterpolationMode::Physics,
+                is_editor: Engine::singleton().is_editor_hint(),
+                has_warned: false,
+                period,
+                damping,
+                response,
+                system,
+                last_output: $system_inner_type_default,
+                base,
+            }
+        });
+    };
+}
+
+generate_shader_parameter_animator!(
+    AnimatorShaderParamVector4,
+    SecondOrderSystemVector4,
+    Vector4,
+    Vector4::ZERO
+);
+
+generate_shader_parameter_animator!(AnimatorShaderParamFloat, SecondOrderSystemFloat, f32, 0.0);
@@ -0,0 +1,824 @@
+use godot::{
+    engine::{notify::NodeNotification, Engine},
+    prelude::*,
+};
+
+use super::{warn_on_period, warn_on_recovery, AnimatorError, InterpolationMode};
+use crate::second_order_systems::*;
+
+/// Animates the whole transform of a Node3D. Position and scale are driven by
+/// the translation parameters, rotation is driven by the rotation parameters.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorTransform3D {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node3D>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node3D>>,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_translation_period)]
+    translation_period: f32,
+    #[export]
+    #[var(get, set = set_translation_damping)]
+    translation_damping: f32,
+    #[export]
+    #[var(get, set = set_translation_response)]
+    translation_response: f32,
+
+    #[export]
+    #[var(get, set = set_rotation_period)]
+    rotation_period: f32,
+    #[export]
+    #[var(get, set = set_rotation_damping)]
+    rotation_damping: f32,
+    #[export]
+    #[var(get, set = set_rotation_response)]
+    rotation_response: f32,
+    // The fastest the follower may turn, in radians per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_angular_speed)]
+    max_angular_speed: f32,
+
+    position_system: SecondOrderSystemVector3,
+    rotation_system: SecondOrderSystemQuaternion,
+    scale_system: SecondOrderSystemVector3,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorTransform3D {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node3D>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node3D>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_translation_period(&mut self, value: f32) {
+        self.translation_period = value;
+        warn_on_period(value);
+        self.position_system.update_period(self.translation_period);
+        self.scale_system.update_period(self.translation_period);
+    }
+    #[func]
+    fn set_translation_damping(&mut self, value: f32) {
+        self.translation_damping = value;
+        self.position_system
+            .update_damping(self.translation_damping);
+        self.scale_system.update_damping(self.translation_damping);
+    }
+    #[func]
+    fn set_translation_response(&mut self, value: f32) {
+        self.translation_response = value;
+        self.position_system
+            .update_response(self.translation_response);
+        self.scale_system.update_response(self.translation_response);
+    }
+    #[func]
+    fn set_rotation_period(&mut self, value: f32) {
+        self.rotation_period = value;
+        warn_on_period(value);
+        self.rotation_system.update_period(self.rotation_period);
+    }
+    #[func]
+    fn set_rotation_damping(&mut self, value: f32) {
+        self.rotation_damping = value;
+        self.rotation_system.update_damping(self.rotation_damping);
+    }
+    #[func]
+    fn set_rotation_response(&mut self, value: f32) {
+        self.rotation_response = value;
+        self.rotation_system.update_response(self.rotation_response);
+    }
+    /// Sets the three translation parameters at once, recomputing the systems a single time.
+    #[func]
+    fn set_translation_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.translation_period = period;
+        warn_on_period(period);
+        self.translation_damping = damping;
+        self.translation_response = response;
+        self.position_system
+            .update_params(period, damping, response);
+        self.scale_system.update_params(period, damping, response);
+    }
+    /// Sets the three rotation parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_rotation_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.rotation_period = period;
+        warn_on_period(period);
+        self.rotation_damping = damping;
+        self.rotation_response = response;
+        self.rotation_system
+            .update_params(period, damping, response);
+    }
+    /// Sets the same parameters to the translation and the rotation.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.set_translation_params(period, damping, response);
+        self.set_rotation_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_angular_speed(&mut self, value: f32) {
+        self.max_angular_speed = value.max(0.0);
+        self.rotation_system
+            .update_max_speed(self.max_angular_speed);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let (target_position, target_rotation, target_scale) =
+            decompose_transform_3d(self.target.as_ref().unwrap().get_transform());
+        let (follower_position, follower_rotation, follower_scale) =
+            decompose_transform_3d(self.follower.as_ref().unwrap().get_transform());
+
+        self.position_system.update_initial_values(
+            target_position,
+            follower_position,
+            Vector3::ZERO,
+        );
+        self.rotation_system.update_initial_values(
+            target_rotation,
+            follower_rotation,
+            SecondOrderSystemQuaternion::zero_derivative(),
+        );
+        self.scale_system
+            .update_initial_values(target_scale, follower_scale, Vector3::ZERO);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let (position, rotation, scale) =
+            decompose_transform_3d(self.target.as_ref().unwrap().get_transform());
+
+        let position = self.position_system.update(position, delta);
+        let rotation = self.rotation_system.update(rotation, delta);
+        let scale = self.scale_system.update(scale, delta);
+        warn_on_recovery(
+            self.position_system.take_recovered()
+                | self.rotation_system.take_recovered()
+                | self.scale_system.take_recovered(),
+        );
+
+        self.follower
+            .as_mut()
+            .unwrap()
+            .set_transform(compose_transform_3d(position, rotation, scale));
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        if self.target.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        }
+        if self.follower.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorTransform3D, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        translation_period: period,
+        translation_damping: damping,
+        translation_response: response,
+        rotation_period: period,
+        rotation_damping: damping,
+        rotation_response: response,
+        max_angular_speed: 0.0,
+        position_system: SecondOrderSystemVector3::new(period, damping, response),
+        rotation_system: SecondOrderSystemQuaternion::new(period, damping, response),
+        scale_system: SecondOrderSystemVector3::new(period, damping, response),
+        base,
+    }
+});
+
+/// Animates the basis of a Node3D, i.e. its rotation and scale together. Unlike
+/// AnimatorRotation3D it keeps a non-uniform scale, but the shear of the basis is dropped.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorBasis3D {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node3D>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node3D>>,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+    // The fastest the follower may turn, in radians per second. Zero means no limit.
+    #[export]
+    #[var(get, set = set_max_angular_speed)]
+    max_angular_speed: f32,
+
+    system: SecondOrderSystemBasis,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorBasis3D {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node3D>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node3D>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self.system.update_period(self.period);
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.system.update_damping(self.damping);
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.system.update_response(self.response);
+    }
+    /// Sets the three parameters at once, recomputing the system a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self.system.update_params(period, damping, response);
+    }
+    #[func]
+    fn set_max_angular_speed(&mut self, value: f32) {
+        self.max_angular_speed = value.max(0.0);
+        self.system.update_max_angular_speed(self.max_angular_speed);
+    }
+
+    fn _update_initial_values(&mut self) {
+        self.system.update_initial_values(
+            self.target.as_ref().unwrap().get_basis(),
+            self.follower.as_ref().unwrap().get_basis(),
+        );
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let basis = self
+            .system
+            .update(self.target.as_ref().unwrap().get_basis(), delta);
+        warn_on_recovery(self.system.take_recovered());
+
+        self.follower.as_mut().unwrap().set_basis(basis);
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        if self.target.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        }
+        if self.follower.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorBasis3D, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        max_angular_speed: 0.0,
+        system: SecondOrderSystemBasis::new(period, damping, response),
+        base,
+    }
+});
+
+/// Animates position, rotation, scale and skew of a Node2D. All channels share
+/// the same parameters, unless a channel overrides them with its own.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+struct AnimatorTransform2D {
+    #[export]
+    #[var(get, set = set_follower)]
+    follower: Option<Gd<Node2D>>,
+    #[export]
+    #[var(get, set = set_target)]
+    target: Option<Gd<Node2D>>,
+
+    #[export]
+    #[var(get, set = set_active)]
+    active: bool,
+    #[export]
+    #[var(get, set = set_run_in_editor)]
+    run_in_editor: bool,
+    #[export]
+    #[var(get, set = set_interpolation_mode)]
+    interpolation_mode: InterpolationMode,
+    // Can't change while the game runs, so it's not queried from the engine every frame.
+    is_editor: bool,
+    has_warned: bool,
+
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+
+    #[export]
+    #[var(get, set = set_position_override)]
+    position_override: bool,
+    #[export]
+    #[var(get, set = set_position_period)]
+    position_period: f32,
+    #[export]
+    #[var(get, set = set_position_damping)]
+    position_damping: f32,
+    #[export]
+    #[var(get, set = set_position_response)]
+    position_response: f32,
+
+    #[export]
+    #[var(get, set = set_rotation_override)]
+    rotation_override: bool,
+    #[export]
+    #[var(get, set = set_rotation_period)]
+    rotation_period: f32,
+    #[export]
+    #[var(get, set = set_rotation_damping)]
+    rotation_damping: f32,
+    #[export]
+    #[var(get, set = set_rotation_response)]
+    rotation_response: f32,
+
+    #[export]
+    #[var(get, set = set_scale_override)]
+    scale_override: bool,
+    #[export]
+    #[var(get, set = set_scale_period)]
+    scale_period: f32,
+    #[export]
+    #[var(get, set = set_scale_damping)]
+    scale_damping: f32,
+    #[export]
+    #[var(get, set = set_scale_response)]
+    scale_response: f32,
+
+    #[export]
+    #[var(get, set = set_skew_override)]
+    skew_override: bool,
+    #[export]
+    #[var(get, set = set_skew_period)]
+    skew_period: f32,
+    #[export]
+    #[var(get, set = set_skew_damping)]
+    skew_damping: f32,
+    #[export]
+    #[var(get, set = set_skew_response)]
+    skew_response: f32,
+
+    position_system: SecondOrderSystemVector2,
+    rotation_system: SecondOrderSystemAngle,
+    scale_system: SecondOrderSystemVector2,
+    skew_system: SecondOrderSystemAngle,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorTransform2D {
+    #[func]
+    fn set_follower(&mut self, value: Option<Gd<Node2D>>) {
+        self.follower = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_target(&mut self, value: Option<Gd<Node2D>>) {
+        self.target = value;
+        self.base_mut().update_configuration_warnings();
+    }
+    #[func]
+    fn set_active(&mut self, value: bool) {
+        if self.active != value {
+            self.active = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_run_in_editor(&mut self, value: bool) {
+        if self.run_in_editor != value {
+            self.run_in_editor = value;
+        }
+
+        if self.active && self._validate().is_ok() {
+            self._update_initial_values();
+        }
+    }
+    #[func]
+    fn set_interpolation_mode(&mut self, value: InterpolationMode) {
+        if self.interpolation_mode != value {
+            self.interpolation_mode = value;
+        }
+    }
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self._update_parameters();
+    }
+    /// Sets the three shared parameters at once, recomputing the systems a single time.
+    #[func]
+    fn set_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.period = period;
+        warn_on_period(period);
+        self.damping = damping;
+        self.response = response;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_position_override(&mut self, value: bool) {
+        self.position_override = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_position_period(&mut self, value: f32) {
+        self.position_period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_position_damping(&mut self, value: f32) {
+        self.position_damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_position_response(&mut self, value: f32) {
+        self.position_response = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_rotation_override(&mut self, value: bool) {
+        self.rotation_override = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_rotation_period(&mut self, value: f32) {
+        self.rotation_period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_rotation_damping(&mut self, value: f32) {
+        self.rotation_damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_rotation_response(&mut self, value: f32) {
+        self.rotation_response = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_scale_override(&mut self, value: bool) {
+        self.scale_override = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_scale_period(&mut self, value: f32) {
+        self.scale_period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_scale_damping(&mut self, value: f32) {
+        self.scale_damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_scale_response(&mut self, value: f32) {
+        self.scale_response = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_skew_override(&mut self, value: bool) {
+        self.skew_override = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_skew_period(&mut self, value: f32) {
+        self.skew_period = value;
+        warn_on_period(value);
+        self._update_parameters();
+    }
+    #[func]
+    fn set_skew_damping(&mut self, value: f32) {
+        self.skew_damping = value;
+        self._update_parameters();
+    }
+    #[func]
+    fn set_skew_response(&mut self, value: f32) {
+        self.skew_response = value;
+        self._update_parameters();
+    }
+
+    fn _update_parameters(&mut self) {
+        let shared = (self.period, self.damping, self.response);
+        let select = |is_overridden: bool, overridden: (f32, f32, f32)| {
+            if is_overridden {
+                overridden
+            } else {
+                shared
+            }
+        };
+
+        let (period, damping, response) = select(
+            self.position_override,
+            (
+                self.position_period,
+                self.position_damping,
+                self.position_response,
+            ),
+        );
+        self.position_system
+            .update_params(period, damping, response);
+
+        let (period, damping, response) = select(
+            self.rotation_override,
+            (
+                self.rotation_period,
+                self.rotation_damping,
+                self.rotation_response,
+            ),
+        );
+        self.rotation_system
+            .update_params(period, damping, response);
+
+        let (period, damping, response) = select(
+            self.scale_override,
+            (self.scale_period, self.scale_damping, self.scale_response),
+        );
+        self.scale_system.update_params(period, damping, response);
+
+        let (period, damping, response) = select(
+            self.skew_override,
+            (self.skew_period, self.skew_damping, self.skew_response),
+        );
+        self.skew_system.update_params(period, damping, response);
+    }
+
+    fn _update_initial_values(&mut self) {
+        let target = self.target.as_ref().unwrap();
+        let follower = self.follower.as_ref().unwrap();
+
+        self.position_system.update_initial_values(
+            target.get_position(),
+            follower.get_position(),
+            Vector2::ZERO,
+        );
+        self.rotation_system.update_initial_values(
+            target.get_rotation(),
+            follower.get_rotation(),
+            0.0,
+        );
+        self.scale_system.update_initial_values(
+            target.get_scale(),
+            follower.get_scale(),
+            Vector2::ZERO,
+        );
+        self.skew_system
+            .update_initial_values(target.get_skew(), follower.get_skew(), 0.0);
+    }
+
+    fn _update(&mut self, delta: f64) {
+        let target = self.target.as_ref().unwrap();
+
+        let position = self.position_system.update(target.get_position(), delta);
+        let rotation = self.rotation_system.update(target.get_rotation(), delta);
+        let scale = self.scale_system.update(target.get_scale(), delta);
+        let skew = self.skew_system.update(target.get_skew(), delta);
+        warn_on_recovery(
+            self.position_system.take_recovered()
+                | self.rotation_system.take_recovered()
+                | self.scale_system.take_recovered()
+                | self.skew_system.take_recovered(),
+        );
+
+        let follower = self.follower.as_mut().unwrap();
+        follower.set_position(position);
+        follower.set_rotation(rotation);
+        follower.set_scale(scale);
+        follower.set_skew(skew);
+    }
+
+    /// Updates the animation by delta seconds, e.g. when the interpolation mode is Manual.
+    #[func]
+    fn advance(&mut self, delta: f64) {
+        if let Err(err) = self._validate() {
+            godot_warn!("Animator error: {}", err);
+            return;
+        }
+
+        self._update(delta);
+    }
+
+    fn _validate(&self) -> Result<(), AnimatorError> {
+        if self.target.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("target"));
+        }
+        if self.follower.is_none() {
+            return Err(AnimatorError::NodeNotSpecified("follower"));
+        }
+
+        Ok(())
+    }
+}
+
+impl_animator_callbacks!(AnimatorTransform2D, |base| {
+    let (period, damping, response) = (1.0, 0.5, 2.0);
+
+    Self {
+        follower: None,
+        target: None,
+        active: true,
+        run_in_editor: false,
+        interpolation_mode: InterpolationMode::Physics,
+        is_editor: Engine::singleton().is_editor_hint(),
+        has_warned: false,
+        period,
+        damping,
+        response,
+        position_override: false,
+        position_period: period,
+        position_damping: damping,
+        position_response: response,
+        rotation_override: false,
+        rotation_period: period,
+        rotation_damping: damping,
+        rotation_response: response,
+        scale_override: false,
+        scale_period: period,
+        scale_damping: damping,
+        scale_response: response,
+        skew_override: false,
+        skew_period: period,
+        skew_damping: damping,
+        skew_response: response,
+        position_system: SecondOrderSystemVector2::new(period, damping, response),
+        rotation_system: SecondOrderSystemAngle::new(period, damping, response),
+        scale_system: SecondOrderSystemVector2::new(period, damping, response),
+        skew_system: SecondOrderSystemAngle::new(period, damping, response),
+        base,
+    }
+});
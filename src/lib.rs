@@ -1,6 +1,8 @@
 use godot::prelude::*;
 
 mod animators;
+mod parameters;
+mod scriptable_systems;
 mod second_order_systems;
 
 /// DGExtension entry
@@ -0,0 +1,131 @@
+use godot::prelude::*;
+
+/// The group AnimatorDefaults joins, so the animators find it anywhere in the tree.
+pub const DEFAULTS_GROUP: &str = "animator_defaults";
+
+/// Period, damping and response shared between animators. Emits changed whenever
+/// any of them is modified, so the animators using it can pick the new values up.
+#[derive(GodotClass)]
+#[class(tool, base=Resource)]
+pub struct SecondOrderParameters {
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+
+    base: Base<Resource>,
+}
+
+#[godot_api]
+impl SecondOrderParameters {
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        self.base_mut().emit_changed();
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self.base_mut().emit_changed();
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self.base_mut().emit_changed();
+    }
+
+    pub fn values(&self) -> (f32, f32, f32) {
+        (self.period, self.damping, self.response)
+    }
+}
+
+#[godot_api]
+impl IResource for SecondOrderParameters {
+    fn init(base: Base<Resource>) -> Self {
+        Self {
+            period: 1.0,
+            damping: 0.5,
+            response: 2.0,
+            base,
+        }
+    }
+}
+
+/// Project-wide period, damping and response for the animators with use_global_defaults,
+/// e.g. as an autoload. Emits defaults_changed whenever any of them is modified,
+/// so the whole project can be retuned at runtime from one place.
+#[derive(GodotClass)]
+#[class(tool, base=Node)]
+pub struct AnimatorDefaults {
+    #[export]
+    #[var(get, set = set_period)]
+    period: f32,
+    #[export]
+    #[var(get, set = set_damping)]
+    damping: f32,
+    #[export]
+    #[var(get, set = set_response)]
+    response: f32,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AnimatorDefaults {
+    #[signal]
+    fn defaults_changed(period: f32, damping: f32, response: f32);
+
+    #[func]
+    fn set_period(&mut self, value: f32) {
+        self.period = value;
+        self._emit_defaults_changed();
+    }
+    #[func]
+    fn set_damping(&mut self, value: f32) {
+        self.damping = value;
+        self._emit_defaults_changed();
+    }
+    #[func]
+    fn set_response(&mut self, value: f32) {
+        self.response = value;
+        self._emit_defaults_changed();
+    }
+
+    pub fn values(&self) -> (f32, f32, f32) {
+        (self.period, self.damping, self.response)
+    }
+
+    // The values are passed along, as the animators can't bind the node while it emits.
+    fn _emit_defaults_changed(&mut self) {
+        let (period, damping, response) = self.values();
+        self.base_mut().emit_signal(
+            "defaults_changed".into(),
+            &[
+                period.to_variant(),
+                damping.to_variant(),
+                response.to_variant(),
+            ],
+        );
+    }
+}
+
+#[godot_api]
+impl INode for AnimatorDefaults {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            period: 1.0,
+            damping: 0.5,
+            response: 2.0,
+            base,
+        }
+    }
+
+    fn enter_tree(&mut self) {
+        self.base_mut().add_to_group(DEFAULTS_GROUP.into());
+    }
+}
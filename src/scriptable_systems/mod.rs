@@ -0,0 +1,81 @@
+use godot::prelude::*;
+
+use crate::second_order_systems::*;
+
+macro_rules! generate_scriptable_system {
+    // This macro generates RefCounted classes wrapping the second-order systems,
+    // so they can be used from scripts without an animator node.
+    // Parameters:
+    // $class_name: The name of the generated class.
+    // $system_type: The type of the wrapped second-order system.
+    // $system_inner_type: The type of the values the system works with (e.g., Vector3).
+    ($class_name:ident, $system_type:ty, $system_inner_type:ty) => {
+        #[derive(GodotClass)]
+        #[class(base=RefCounted)]
+        struct $class_name {
+            system: $system_type,
+
+            base: Base<RefCounted>,
+        }
+
+        #[godot_api]
+        impl $class_name {
+            #[func]
+            fn new_system(period: f32, damping: f32, response: f32) -> Gd<Self> {
+                Gd::from_init_fn(|base| Self {
+                    system: <$system_type>::new(period, damping, response),
+                    base,
+                })
+            }
+            #[func]
+            fn update(&mut self, input: $system_inner_type, delta: f64) -> $system_inner_type {
+                self.system.update(input, delta)
+            }
+            #[func]
+            fn snap_to(&mut self, value: $system_inner_type) {
+                self.system.snap_to(value);
+            }
+            #[func]
+            fn get_velocity(&self) -> <$system_inner_type as SystemVelocity>::Velocity {
+                self.system.velocity()
+            }
+            #[func]
+            fn update_period(&mut self, period: f32) {
+                self.system.update_period(period);
+            }
+            #[func]
+            fn update_damping(&mut self, damping: f32) {
+                self.system.update_damping(damping);
+            }
+            #[func]
+            fn update_response(&mut self, response: f32) {
+                self.system.update_response(response);
+            }
+            #[func]
+            fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+                self.system.update_params(period, damping, response);
+            }
+        }
+
+        #[godot_api]
+        impl IRefCounted for $class_name {
+            fn init(base: Base<RefCounted>) -> Self {
+                let (period, damping, response) = (1.0, 0.5, 2.0);
+
+                Self {
+                    system: <$system_type>::new(period, damping, response),
+                    base,
+                }
+            }
+        }
+    };
+}
+
+generate_scriptable_system!(SecondOrderFloat, SecondOrderSystemFloat, f32);
+generate_scriptable_system!(SecondOrderVector3, SecondOrderSystemVector3, Vector3);
+// The quaternion system renormalizes its output every step, so scripts always get a unit quaternion.
+generate_scriptable_system!(
+    SecondOrderQuaternion,
+    SecondOrderSystemQuaternion,
+    Quaternion
+);
@@ -1,9 +1,402 @@
 use std::f32::consts::PI;
 
-use godot::builtin::{Quaternion, Vector2, Vector3};
+use godot::{
+    builtin::{Basis, Color, Quaternion, Rect2, Transform3D, Vector2, Vector3, Vector4},
+    log::godot_warn,
+    prelude::{Export, GodotConvert, Var},
+};
+
+/// The period the systems work with, and whether it can't be used as is. A period that is not
+/// positive makes the output snap to the input, as its coefficients would be infinite.
+/// The systems don't depend on the engine, so warning about it is left to the caller.
+#[inline]
+pub fn checked_period(period: f32) -> (f32, bool) {
+    if period.is_nan() || period <= 0.0 {
+        return (0.0, true);
+    }
+
+    (period, false)
+}
+
+/// Replaces an overflowed coefficient by the largest finite one of the same sign.
+#[inline]
+fn finite_k(k: f32) -> f32 {
+    k.clamp(-f32::MAX, f32::MAX)
+}
+
+/// Whether the output of a system with these parameters converges. The poles only depend on
+/// the period and the damping, the response only shapes the start of the motion.
+pub fn is_stable(period: f32, damping: f32, _response: f32) -> bool {
+    period >= 0.0 && damping >= 0.0
+}
+
+/// The largest change of the input that still counts as a static target for the analytic step.
+const STATIC_INPUT_EPSILON: f32 = 1e-6;
+
+/// How the systems integrate their motion over a step.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone)]
+#[godot(via = GString)]
+pub enum IntegrationMethod {
+    /// Stable and cheap, but loses accuracy for snappy systems at low framerates.
+    SemiImplicitEuler,
+    /// Four evaluations per step, much closer to the exact motion. Only used by the float
+    /// and vector systems, the others keep the semi-implicit Euler.
+    RK4,
+}
+
+/// The space the color system interpolates in. Hsv keeps the hue of the transition,
+/// instead of passing through grays, and takes the shortest way around the hue circle.
+#[derive(GodotConvert, Var, Export, PartialEq, Eq, Debug, Copy, Clone, Default)]
+#[godot(via = GString)]
+pub enum ColorSpace {
+    #[default]
+    Rgb,
+    Hsv,
+}
+
+/// Converts the derivative a system stores into the velocity it reports.
+pub trait SystemVelocity {
+    type Velocity;
+
+    fn into_velocity(self) -> Self::Velocity;
+
+    /// The derivative a system stores for the velocity, the inverse of into_velocity.
+    fn from_velocity(velocity: Self::Velocity) -> Self;
+
+    /// The value time seconds ago, when it has been moving with the velocity.
+    fn rewind(self, velocity: Self::Velocity, time: f32) -> Self;
+}
+
+macro_rules! impl_system_velocity_for_linear_types {
+    ($($type:ty),*) => {
+        $(
+            impl SystemVelocity for $type {
+                type Velocity = $type;
+
+                #[inline]
+                fn into_velocity(self) -> Self::Velocity {
+                    self
+                }
+
+                #[inline]
+                fn from_velocity(velocity: Self::Velocity) -> Self {
+                    velocity
+                }
+
+                #[inline]
+                fn rewind(self, velocity: Self::Velocity, time: f32) -> Self {
+                    self - velocity * time
+                }
+            }
+        )*
+    };
+}
+
+impl_system_velocity_for_linear_types!(f32, Vector2, Vector3, Vector4);
+
+impl SystemVelocity for Color {
+    type Velocity = Color;
+
+    #[inline]
+    fn into_velocity(self) -> Self::Velocity {
+        self
+    }
+
+    #[inline]
+    fn from_velocity(velocity: Self::Velocity) -> Self {
+        velocity
+    }
+
+    #[inline]
+    fn rewind(self, velocity: Self::Velocity, time: f32) -> Self {
+        vector4_to_color(color_to_vector4(self) - color_to_vector4(velocity) * time)
+    }
+}
+
+impl SystemVelocity for Quaternion {
+    type Velocity = Vector3;
+
+    /// The derivative lives in the log space, where a half of the rotation angle is stored,
+    /// so the angular velocity in radians per second is twice its vector part.
+    #[inline]
+    fn into_velocity(self) -> Self::Velocity {
+        2.0 * Vector3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn from_velocity(velocity: Self::Velocity) -> Self {
+        let half = 0.5 * velocity;
+        Quaternion::new(half.x, half.y, half.z, 0.0)
+    }
+
+    #[inline]
+    fn rewind(self, velocity: Self::Velocity, time: f32) -> Self {
+        let log = -0.5 * time * velocity;
+        Quaternion::new(log.x, log.y, log.z, 0.0).to_exp() * self
+    }
+}
+
+/// Measures the values and derivatives a system works with.
+pub trait SystemMetric {
+    fn distance(self, other: Self) -> f32;
+
+    /// The magnitude of a derivative of this type, per second.
+    fn speed(derivative: Self) -> f32;
+}
+
+impl SystemMetric for f32 {
+    #[inline]
+    fn distance(self, other: Self) -> f32 {
+        (self - other).abs()
+    }
+
+    #[inline]
+    fn speed(derivative: Self) -> f32 {
+        derivative.abs()
+    }
+}
+
+macro_rules! impl_system_metric_for_vector_types {
+    ($($type:ty),*) => {
+        $(
+            impl SystemMetric for $type {
+                #[inline]
+                fn distance(self, other: Self) -> f32 {
+                    (self - other).length()
+                }
+
+                #[inline]
+                fn speed(derivative: Self) -> f32 {
+                    derivative.length()
+                }
+            }
+        )*
+    };
+}
+
+impl_system_metric_for_vector_types!(Vector2, Vector3, Vector4);
+
+impl SystemMetric for Color {
+    #[inline]
+    fn distance(self, other: Self) -> f32 {
+        color_to_vector4(self).distance(color_to_vector4(other))
+    }
+
+    #[inline]
+    fn speed(derivative: Self) -> f32 {
+        color_to_vector4(derivative).length()
+    }
+}
+
+impl SystemMetric for Quaternion {
+    /// The angle between two orientations in radians.
+    #[inline]
+    fn distance(self, other: Self) -> f32 {
+        2.0 * self.dot(other).abs().min(1.0).acos()
+    }
+
+    #[inline]
+    fn speed(derivative: Self) -> f32 {
+        derivative.into_velocity().length()
+    }
+}
+
+/// Shortens a step of the system, when its output moves faster than allowed.
+pub trait SystemLimit {
+    /// The value the given fraction of the way to the other one, along the shortest path.
+    fn interpolate(self, other: Self, weight: f32) -> Self;
+
+    /// A derivative of this type scaled by the factor.
+    fn scale_derivative(derivative: Self, factor: f32) -> Self;
+}
+
+macro_rules! impl_system_limit_for_linear_types {
+    ($($type:ty),*) => {
+        $(
+            impl SystemLimit for $type {
+                #[inline]
+                fn interpolate(self, other: Self, weight: f32) -> Self {
+                    self + (other - self) * weight
+                }
+
+                #[inline]
+                fn scale_derivative(derivative: Self, factor: f32) -> Self {
+                    derivative * factor
+                }
+            }
+        )*
+    };
+}
+
+impl_system_limit_for_linear_types!(f32, Vector2, Vector3, Vector4);
+
+impl SystemLimit for Color {
+    #[inline]
+    fn interpolate(self, other: Self, weight: f32) -> Self {
+        let from = color_to_vector4(self);
+        vector4_to_color(from + (color_to_vector4(other) - from) * weight)
+    }
+
+    #[inline]
+    fn scale_derivative(derivative: Self, factor: f32) -> Self {
+        vector4_to_color(color_to_vector4(derivative) * factor)
+    }
+}
+
+impl SystemLimit for Quaternion {
+    #[inline]
+    fn interpolate(self, other: Self, weight: f32) -> Self {
+        self.slerp(other, weight)
+    }
+
+    /// The derivative lives in the log space, where scaling it scales the angular velocity.
+    #[inline]
+    fn scale_derivative(derivative: Self, factor: f32) -> Self {
+        Quaternion::new(
+            derivative.x * factor,
+            derivative.y * factor,
+            derivative.z * factor,
+            derivative.w * factor,
+        )
+    }
+}
+
+/// Detects a state broken by NaN or infinity, which the system would never recover from.
+pub trait SystemFinite {
+    fn all_finite(self) -> bool;
+}
+
+macro_rules! impl_system_finite {
+    ($($type:ty: |$value:ident| [$($component:expr),*]),*) => {
+        $(
+            impl SystemFinite for $type {
+                #[inline]
+                fn all_finite(self) -> bool {
+                    let $value = self;
+                    true $(&& $component.is_finite())*
+                }
+            }
+        )*
+    };
+}
+
+impl_system_finite!(
+    f32: |value| [value],
+    Vector2: |value| [value.x, value.y],
+    Vector3: |value| [value.x, value.y, value.z],
+    Vector4: |value| [value.x, value.y, value.z, value.w],
+    Color: |value| [value.r, value.g, value.b, value.a],
+    Quaternion: |value| [value.x, value.y, value.z, value.w]
+);
+
+/// Access to the components of a vector, which are stepped one by one with anisotropic parameters.
+pub trait SystemComponents {
+    fn component(self, axis: usize) -> f32;
+    fn set_component(&mut self, axis: usize, value: f32);
+}
+
+macro_rules! impl_system_components {
+    ($($type:ty: [$($axis:literal => $field:ident),*]),*) => {
+        $(
+            impl SystemComponents for $type {
+                #[inline]
+                fn component(self, axis: usize) -> f32 {
+                    match axis {
+                        $( $axis => self.$field, )*
+                        _ => panic!("The axis {} is out of range.", axis),
+                    }
+                }
+
+                #[inline]
+                fn set_component(&mut self, axis: usize, value: f32) {
+                    match axis {
+                        $( $axis => self.$field = value, )*
+                        _ => panic!("The axis {} is out of range.", axis),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_system_components!(
+    Vector2: [0 => x, 1 => y],
+    Vector3: [0 => x, 1 => y, 2 => z]
+);
+
+/// The surface shared by all the systems, to write code generic over them.
+pub trait SecondOrderSystem<T> {
+    type Velocity;
+
+    fn update(&mut self, input: T, delta: f64) -> T;
+    fn update_period(&mut self, period: f32);
+    fn update_damping(&mut self, damping: f32);
+    fn update_response(&mut self, response: f32);
+    fn update_params(&mut self, period: f32, damping: f32, response: f32);
+    fn snap_to(&mut self, value: T);
+    fn velocity(&self) -> Self::Velocity;
+    fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool;
+    fn take_recovered(&mut self) -> bool;
+}
+
+/// Implements SecondOrderSystem by forwarding to the inherent methods of the system.
+macro_rules! impl_second_order_system {
+    ($name:ident, $type:ty, $velocity:ty) => {
+        impl SecondOrderSystem<$type> for $name {
+            type Velocity = $velocity;
+
+            #[inline]
+            fn update(&mut self, input: $type, delta: f64) -> $type {
+                $name::update(self, input, delta)
+            }
+
+            fn update_period(&mut self, period: f32) {
+                $name::update_period(self, period)
+            }
+
+            fn update_damping(&mut self, damping: f32) {
+                $name::update_damping(self, damping)
+            }
+
+            fn update_response(&mut self, response: f32) {
+                $name::update_response(self, response)
+            }
+
+            fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+                $name::update_params(self, period, damping, response)
+            }
+
+            fn snap_to(&mut self, value: $type) {
+                $name::snap_to(self, value)
+            }
+
+            fn velocity(&self) -> $velocity {
+                $name::velocity(self)
+            }
+
+            fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool {
+                $name::is_settled(self, position_epsilon, velocity_epsilon)
+            }
+
+            fn take_recovered(&mut self) -> bool {
+                $name::take_recovered(self)
+            }
+        }
+    };
+}
 
 macro_rules! generate_systems_for_simple_types {
-    ( $name:ident, $type:ty, $default:expr, $interpolation_step:ident ) => {
+    (
+        $name:ident,
+        $type:ty,
+        $default:expr,
+        $derivative_default:expr,
+        $interpolation_step:ident
+        $(, rk4: $rk4_interpolation_step:ident )?
+        $(, analytic: $analytic_interpolation_step:ident )?
+        $(, space: $space_type:ty, $to_space:ident, $from_space:ident )?
+        $(, anisotropic: $axes:literal )?
+    ) => {
         pub struct $name {
             period: f32,
             damping: f32,
@@ -14,10 +407,23 @@ macro_rules! generate_systems_for_simple_types {
             yd: $type,
 
             k: (f32, f32, f32),
+            integration_method: IntegrationMethod,
+            analytic_when_static: bool,
+            unstable_warned: bool,
+            // A reset of a state that wasn't finite, not yet taken by take_recovered.
+            recovered: bool,
+            recovery_reported: bool,
+            // The fastest the output may move per second, zero means no limit.
+            max_speed: f32,
+            // The state is kept in this space, the inputs and outputs are converted.
+            $( space: $space_type, )?
+            // The coefficients of every component, when they have parameters of their own.
+            $( axis_k: Option<[(f32, f32, f32); $axes]>, )?
         }
 
         impl $name {
             pub fn new(period: f32, damping: f32, response: f32) -> Self {
+                let period = checked_period(period).0;
                 let k = Self::calculate_k(period, damping, response);
 
                 Self {
@@ -26,13 +432,29 @@ macro_rules! generate_systems_for_simple_types {
                     response,
                     xp: $default,
                     y: $default,
-                    yd: $default,
+                    yd: $derivative_default,
                     k,
+                    integration_method: IntegrationMethod::SemiImplicitEuler,
+                    analytic_when_static: false,
+                    unstable_warned: false,
+                    recovered: false,
+                    recovery_reported: false,
+                    max_speed: 0.0,
+                    $( space: <$space_type>::default(), )?
+                    $( axis_k: None::<[(f32, f32, f32); $axes]>, )?
                 }
             }
 
+            /// Creates a system already at rest at the initial value, so it doesn't need
+            /// a separate update_initial_values call.
+            pub fn with_initial(period: f32, damping: f32, response: f32, initial: $type) -> Self {
+                let mut system = Self::new(period, damping, response);
+                system.snap_to(initial);
+                system
+            }
+
             pub fn update_period(&mut self, period: f32) {
-                self.period = period;
+                self.period = checked_period(period).0;
                 self.update_k();
             }
 
@@ -46,46 +468,324 @@ macro_rules! generate_systems_for_simple_types {
                 self.update_k();
             }
 
+            /// Sets all the parameters, recomputing the coefficients once.
+            pub fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+                self.period = checked_period(period).0;
+                self.damping = damping;
+                self.response = response;
+                self.update_k();
+            }
+
+            pub fn update_integration_method(&mut self, integration_method: IntegrationMethod) {
+                self.integration_method = integration_method;
+            }
+
+            /// Caps how fast the output moves, in units per second, or radians per second
+            /// for rotations. Zero removes the cap.
+            pub fn update_max_speed(&mut self, max_speed: f32) {
+                self.max_speed = max_speed.max(0.0);
+            }
+
+            /// Steps the system with the exact solution while the input stays still.
+            /// Only the float and vector systems have one, the others ignore the flag.
+            pub fn update_analytic_when_static(&mut self, analytic_when_static: bool) {
+                self.analytic_when_static = analytic_when_static;
+            }
+
+            $(
+                pub fn space(&self) -> $space_type {
+                    self.space
+                }
+
+                /// Switches the space the system works in, keeping the output but stopping it.
+                pub fn update_space(&mut self, space: $space_type) {
+                    let previous = $from_space(self.xp, self.space);
+                    let current = $from_space(self.y, self.space);
+
+                    self.space = space;
+                    self.update_initial_values(previous, current, $derivative_default);
+                }
+            )?
+
+            $(
+                /// Gives every component its own parameters, e.g. a fast horizontal follow
+                /// and a slow vertical one. The coefficients are computed per component.
+                pub fn update_anisotropic_params(&mut self, period: $type, damping: $type, response: $type) {
+                    let mut axis_k = [(0.0, 0.0, 0.0); $axes];
+                    for (axis, k) in axis_k.iter_mut().enumerate() {
+                        *k = Self::calculate_k(
+                            checked_period(period.component(axis)).0,
+                            damping.component(axis),
+                            response.component(axis),
+                        );
+                    }
+
+                    self.axis_k = Some(axis_k);
+                }
+
+                /// Goes back to the parameters shared by all the components.
+                pub fn clear_anisotropic_params(&mut self) {
+                    self.axis_k = None::<[(f32, f32, f32); $axes]>;
+                }
+            )?
+
             pub fn update_initial_values(
                 &mut self,
                 previous: $type,
                 current: $type,
                 current_derevative: $type,
             ) {
+                $(
+                    let current = $to_space(current, self.space, $default);
+                    let previous = $to_space(previous, self.space, current);
+                )?
+
+                self.xp = previous;
+                self.y = current;
+                self.yd = current_derevative;
+            }
+
+            /// The derivative of a system at rest.
+            pub fn zero_derivative() -> $type {
+                $derivative_default
+            }
+
+            pub fn snap_to(&mut self, value: $type) {
+                $( let value = $to_space(value, self.space, self.y); )?
+
+                self.xp = value;
+                self.y = value;
+                self.yd = $derivative_default;
+            }
+
+            /// The previous input, the output and its derivative, e.g. to save and restore them later
+            /// with restore_state. They are in the space the system works in.
+            pub fn state(&self) -> ($type, $type, $type) {
+                (self.xp, self.y, self.yd)
+            }
+
+            /// The input of the last step.
+            pub fn previous_input(&self) -> $type {
+                let previous = self.xp;
+                $( let previous = $from_space(previous, self.space); )?
+
+                previous
+            }
+
+            /// Restores a state returned by state as is.
+            pub fn restore_state(&mut self, previous: $type, current: $type, current_derevative: $type) {
                 self.xp = previous;
                 self.y = current;
                 self.yd = current_derevative;
             }
 
+            /// The coefficients derived from the period, damping and response. The second one
+            /// is used as is only when it's large enough to keep the step stable.
+            pub fn coefficients(&self) -> (f32, f32, f32) {
+                self.k
+            }
+
+            /// The last output, without stepping the system.
+            pub fn current(&self) -> $type {
+                let current = self.y;
+                $( let current = $from_space(current, self.space); )?
+
+                current
+            }
+
+            /// The output time seconds ahead, moving with the current velocity. The state is unchanged.
+            pub fn extrapolate(&self, time: f32) -> $type {
+                let value = self.y.rewind(self.yd.into_velocity(), -time);
+                $( let value = $from_space(value, self.space); )?
+
+                value
+            }
+
+            /// The rate of change of the output per second. It's in the space the system works in,
+            /// e.g. the color system in the Hsv space reports h, s, v per second in r, g, b.
+            pub fn velocity(&self) -> <$type as SystemVelocity>::Velocity {
+                self.yd.into_velocity()
+            }
+
+            /// The magnitude of the velocity per second.
+            pub fn speed(&self) -> f32 {
+                <$type as SystemMetric>::speed(self.yd)
+            }
+
+            /// Whether the output has reached the last input and almost stopped.
+            pub fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool {
+                self.y.distance(self.xp) < position_epsilon && self.speed() < velocity_epsilon
+            }
+
+            /// Whether the state wasn't finite and has been reset to the input since the last call.
+            /// It's only reported the first time, so the caller can warn about it without spamming.
+            pub fn take_recovered(&mut self) -> bool {
+                std::mem::take(&mut self.recovered)
+            }
+
             #[inline]
             fn update_k(&mut self) {
                 self.k = Self::calculate_k(self.period, self.damping, self.response);
+                self.check_stability();
+            }
+
+            /// Pulls the output of the last step back to max_speed from the previous output,
+            /// and slows the derivative down to it, so a jump of the target is followed gradually.
+            #[inline]
+            fn limit_speed(&mut self, previous: $type, d: f32) {
+                if self.max_speed <= 0.0 || d <= 0.0 {
+                    return;
+                }
+
+                let distance = previous.distance(self.y);
+                let max_distance = self.max_speed * d;
+                if distance > max_distance {
+                    self.y = previous.interpolate(self.y, max_distance / distance);
+                }
+
+                let speed = <$type as SystemMetric>::speed(self.yd);
+                if speed > self.max_speed {
+                    self.yd = <$type as SystemLimit>::scale_derivative(self.yd, self.max_speed / speed);
+                }
+            }
+
+            /// Starts over from the input, instead of being stuck with a NaN output forever.
+            #[cold]
+            fn recover(&mut self, input: $type) {
+                if !self.recovery_reported {
+                    self.recovered = true;
+                    self.recovery_reported = true;
+                }
+
+                // The output is the reference of the working space conversion, so it's cleared first.
+                self.y = $default;
+                self.snap_to(input);
+            }
+
+            /// Warns once when the parameters make the system diverge, until they are fixed.
+            fn check_stability(&mut self) {
+                if is_stable(self.period, self.damping, self.response) {
+                    self.unstable_warned = false;
+                    return;
+                }
+
+                if !self.unstable_warned {
+                    godot_warn!(
+                        "Second order system warning: The damping {} is negative, the output will diverge.",
+                        self.damping
+                    );
+                    self.unstable_warned = true;
+                }
             }
 
             #[inline]
             fn calculate_k(period: f32, damping: f32, response: f32) -> (f32, f32, f32) {
-                let (f, z, r) = (period, damping, response);
+                // The coefficients are infinite for a zero period, they are unused as the output snaps.
+                if period <= 0.0 {
+                    return (0.0, 0.0, 0.0);
+                }
+
+                // The period is the natural frequency, so a tiny one is just a very slow system.
+                // Its coefficients are kept finite instead of raising the frequency.
+                let (f, z, r) = (period.max(f32::MIN_POSITIVE), damping, response);
 
                 let k0 = z / (PI * f);
                 let k1 = 1. / ((2. * PI * f) * (2. * PI * f));
                 let k2 = r * z / (2. * PI * f);
 
-                (k0, k1, k2)
+                (finite_k(k0), finite_k(k1), finite_k(k2))
             }
 
             #[inline]
             fn interpolation_step(&mut self, x: $type, d: f32) {
+                $(
+                    let axis_k: Option<[(f32, f32, f32); $axes]> = self.axis_k;
+                    if let Some(axis_k) = axis_k {
+                        // Every component is stepped as a float system with its own coefficients.
+                        let step = match self.integration_method {
+                            IntegrationMethod::SemiImplicitEuler => interpolation_step_float,
+                            IntegrationMethod::RK4 => interpolation_step_float_rk4,
+                        };
+
+                        for (axis, (k1, k2, k3)) in axis_k.into_iter().enumerate() {
+                            let (xp, y, yd) = step(
+                                k1,
+                                k2,
+                                k3,
+                                x.component(axis),
+                                self.xp.component(axis),
+                                self.y.component(axis),
+                                self.yd.component(axis),
+                                d,
+                            );
+
+                            self.xp.set_component(axis, xp);
+                            self.y.set_component(axis, y);
+                            self.yd.set_component(axis, yd);
+                        }
+                        return;
+                    }
+                )?
+
+                if self.period <= 0.0 && d > 0.0 {
+                    (self.xp, self.y, self.yd) = (x, x, $derivative_default);
+                    return;
+                }
+
                 let (k1, k2, k3) = self.k;
+
+                $(
+                    if self.analytic_when_static && x.distance(self.xp) < STATIC_INPUT_EPSILON {
+                        (self.xp, self.y, self.yd) =
+                            $analytic_interpolation_step(k1, k2, x, self.y, self.yd, d);
+                        return;
+                    }
+                )?
+
+                $(
+                    if self.integration_method == IntegrationMethod::RK4 {
+                        (self.xp, self.y, self.yd) =
+                            $rk4_interpolation_step(k1, k2, k3, x, self.xp, self.y, self.yd, d);
+                        return;
+                    }
+                )?
+
                 (self.xp, self.y, self.yd) =
                     $interpolation_step(k1, k2, k3, x, self.xp, self.y, self.yd, d);
             }
 
+            /// Steps the system with the known velocity of the input, instead of estimating it
+            /// from the previous input. It removes a frame of lag and the differentiation noise.
+            #[inline]
+            pub fn update_with_velocity(
+                &mut self,
+                input: $type,
+                input_velocity: <$type as SystemVelocity>::Velocity,
+                delta: f64,
+            ) -> $type {
+                let previous = input.rewind(input_velocity, delta as f32);
+                $( let previous = $to_space(previous, self.space, self.y); )?
+
+                self.xp = previous;
+                self.update(input, delta)
+            }
+
             #[inline]
             pub fn update(&mut self, input: $type, delta: f64) -> $type {
+                if !(self.y.all_finite() && self.yd.all_finite()) {
+                    self.recover(input);
+                }
+
+                $( let input = $to_space(input, self.space, self.y); )?
+
+                let previous = self.y;
                 self.interpolation_step(input, delta as f32);
-                self.y
+                self.limit_speed(previous, delta as f32);
+                self.current()
             }
         }
+
+        impl_second_order_system!($name, $type, <$type as SystemVelocity>::Velocity);
     };
 }
 
@@ -102,6 +802,11 @@ macro_rules! generate_default_interpolation_step {
             mut yd: $type,
             d: f32,
         ) -> ($type, $type, $type) {
+            // A zero delta (e.g. a paused frame) would turn the state into NaN for good.
+            if d <= 0.0 {
+                return (xp, y, yd);
+            }
+
             let xd = (x - xp) / d;
 
             let k2_stable = f32::max(k2, 1.1 * (d * d + 0.5 * d * k1));
@@ -115,54 +820,1007 @@ macro_rules! generate_default_interpolation_step {
     };
 }
 
-#[inline]
-fn interpolation_step_quaternion(
-    k1: f32,
-    k2: f32,
-    k3: f32,
-    mut x: Quaternion,
-    mut xp: Quaternion,
-    mut y: Quaternion,
-    mut yd: Quaternion,
-    d: f32,
-) -> (Quaternion, Quaternion, Quaternion) {
-    if x.dot(y) < 0.0 {
-        x = -x;
-    }
-
-    // We normalized (x * xp.inverse()) and (x * y.inverse()) beacuse there is calculation error,
-    // when quaternion rotations are very close. Normalization is not the fastest solution, but it works.
-
-    let xd = (x * xp.inverse()).normalized().log() / d;
-    let k2_stable = f32::max(k2, 1.1 * (d * d + 0.5 * d * k1));
-
-    xp = x;
-    y = (d * yd).to_exp() * y;
+macro_rules! generate_rk4_interpolation_step {
+    ($name:ident, $type:ty) => {
+        #[inline]
+        fn $name(
+            k1: f32,
+            k2: f32,
+            k3: f32,
+            x: $type,
+            xp: $type,
+            y: $type,
+            yd: $type,
+            d: f32,
+        ) -> ($type, $type, $type) {
+            // A zero delta (e.g. a paused frame) would turn the state into NaN for good.
+            if d <= 0.0 {
+                return (xp, y, yd);
+            }
+
+            // The input moves linearly from xp to x during the step.
+            let xd = (x - xp) / d;
+
+            let k2_stable = f32::max(k2, 1.1 * (d * d + 0.5 * d * k1));
+            let acceleration =
+                |t: f32, y: $type, yd: $type| (xp + xd * t + xd * k3 - y - yd * k1) / k2_stable;
+
+            let half = 0.5 * d;
+
+            let (v1, a1) = (yd, acceleration(0.0, y, yd));
+            let (v2, a2) = (
+                yd + a1 * half,
+                acceleration(half, y + v1 * half, yd + a1 * half),
+            );
+            let (v3, a3) = (
+                yd + a2 * half,
+                acceleration(half, y + v2 * half, yd + a2 * half),
+            );
+            let (v4, a4) = (yd + a3 * d, acceleration(d, y + v3 * d, yd + a3 * d));
+
+            let y = y + (v1 + v2 * 2.0 + v3 * 2.0 + v4) * (d / 6.0);
+            let yd = yd + (a1 + a2 * 2.0 + a3 * 2.0 + a4) * (d / 6.0);
+
+            (x, y, yd)
+        }
+    };
+}
+
+/// The coefficients of the exact solution for a static input after the time t. The offset from
+/// the input and the velocity after t are (a * offset + b * velocity, c * offset + d * velocity),
+/// where the offset and the velocity are the initial ones.
+#[inline]
+fn analytic_coefficients(k1: f32, k2: f32, t: f32) -> (f32, f32, f32, f32) {
+    // k2 * y'' + k1 * y' + y = x, which is a damped oscillator with these natural frequency and damping.
+    let w = 1.0 / k2.sqrt();
+    let z = 0.5 * k1 * w;
+
+    if (z - 1.0).abs() < 1e-4 {
+        let exp = (-w * t).exp();
+        return (
+            (1.0 + w * t) * exp,
+            t * exp,
+            -w * w * t * exp,
+            (1.0 - w * t) * exp,
+        );
+    }
+
+    if z < 1.0 {
+        let sigma = z * w;
+        let wd = w * (1.0 - z * z).sqrt();
+        let (sin, cos) = (wd * t).sin_cos();
+        let exp = (-sigma * t).exp();
+        return (
+            exp * (cos + sigma / wd * sin),
+            exp * sin / wd,
+            -exp * w * w / wd * sin,
+            exp * (cos - sigma / wd * sin),
+        );
+    }
+
+    let root = (z * z - 1.0).sqrt();
+    let (r1, r2) = (-w * (z - root), -w * (z + root));
+    let (exp1, exp2) = ((r1 * t).exp(), (r2 * t).exp());
+    let span = r1 - r2;
+    (
+        (r1 * exp2 - r2 * exp1) / span,
+        (exp1 - exp2) / span,
+        r1 * r2 * (exp2 - exp1) / span,
+        (r1 * exp1 - r2 * exp2) / span,
+    )
+}
+
+macro_rules! generate_analytic_interpolation_step {
+    ($name:ident, $type:ty) => {
+        #[inline]
+        fn $name(k1: f32, k2: f32, x: $type, y: $type, yd: $type, d: f32) -> ($type, $type, $type) {
+            if d <= 0.0 {
+                return (x, y, yd);
+            }
+
+            let (a, b, c, e) = analytic_coefficients(k1, k2, d);
+            let offset = y - x;
+
+            (x, x + offset * a + yd * b, offset * c + yd * e)
+        }
+    };
+}
+
+#[inline]
+fn interpolation_step_quaternion(
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    mut x: Quaternion,
+    mut xp: Quaternion,
+    mut y: Quaternion,
+    mut yd: Quaternion,
+    d: f32,
+) -> (Quaternion, Quaternion, Quaternion) {
+    // A zero delta (e.g. a paused frame) would turn the state into NaN for good.
+    if d <= 0.0 {
+        return (xp, y, yd);
+    }
+
+    if x.dot(y) < 0.0 {
+        x = -x;
+    }
+
+    // We normalized (x * xp.inverse()) and (x * y.inverse()) beacuse there is calculation error,
+    // when quaternion rotations are very close. Normalization is not the fastest solution, but it works.
+
+    let xd = (x * xp.inverse()).normalized().log() / d;
+    let k2_stable = f32::max(k2, 1.1 * (d * d + 0.5 * d * k1));
+
+    xp = x;
+    // Renormalized, so the floating point error doesn't accumulate into a non-unit rotation over time.
+    y = ((d * yd).to_exp() * y).normalized();
     yd += d * ((x * y.inverse()).normalized().log() + k3 * xd - k1 * yd) / k2_stable;
 
     (xp, y, yd)
 }
 
+// Color channels are stepped independently, so the color step simply runs the vector one on RGBA.
+
+#[inline]
+fn interpolation_step_color(
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    x: Color,
+    xp: Color,
+    y: Color,
+    yd: Color,
+    d: f32,
+) -> (Color, Color, Color) {
+    let (xp, y, yd) = interpolation_step_vector4(
+        k1,
+        k2,
+        k3,
+        color_to_vector4(x),
+        color_to_vector4(xp),
+        color_to_vector4(y),
+        color_to_vector4(yd),
+        d,
+    );
+
+    (
+        vector4_to_color(xp),
+        vector4_to_color(y),
+        vector4_to_color(yd),
+    )
+}
+
+/// Converts an RGB color into the space, storing h, s, v in r, g, b for Hsv. The hue is moved
+/// next to the hue of the reference, so the system doesn't see a jump where the hue wraps.
+#[inline]
+fn color_to_space(color: Color, space: ColorSpace, reference: Color) -> Color {
+    match space {
+        ColorSpace::Rgb => color,
+        ColorSpace::Hsv => {
+            let hsv = rgb_to_hsv(color);
+            let hue = hsv.r - reference.r;
+            Color::from_rgba(reference.r + hue - hue.round(), hsv.g, hsv.b, hsv.a)
+        }
+    }
+}
+
+#[inline]
+fn color_from_space(color: Color, space: ColorSpace) -> Color {
+    match space {
+        ColorSpace::Rgb => color,
+        ColorSpace::Hsv => hsv_to_rgb(color),
+    }
+}
+
+/// The hue is in [0, 1), like the one of the Godot Color.
+#[inline]
+fn rgb_to_hsv(color: Color) -> Color {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let range = max - min;
+
+    let hue = if range <= 0.0 {
+        0.0
+    } else if max == color.r {
+        ((color.g - color.b) / range).rem_euclid(6.0)
+    } else if max == color.g {
+        (color.b - color.r) / range + 2.0
+    } else {
+        (color.r - color.g) / range + 4.0
+    };
+    let saturation = if max > 0.0 { range / max } else { 0.0 };
+
+    Color::from_rgba(hue / 6.0, saturation, max, color.a)
+}
+
+#[inline]
+fn hsv_to_rgb(color: Color) -> Color {
+    let (hue, saturation, value) = (color.r.rem_euclid(1.0) * 6.0, color.g, color.b);
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue % 2.0) - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+
+    Color::from_rgba(r + m, g + m, b + m, color.a)
+}
+
+#[inline]
+fn color_to_vector4(color: Color) -> Vector4 {
+    Vector4::new(color.r, color.g, color.b, color.a)
+}
+
+#[inline]
+fn vector4_to_color(vector: Vector4) -> Color {
+    Color::from_rgba(vector.x, vector.y, vector.z, vector.w)
+}
+
+#[inline]
+fn interpolation_step_angle(
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    x: f32,
+    xp: f32,
+    y: f32,
+    yd: f32,
+    d: f32,
+) -> (f32, f32, f32) {
+    // The target is moved next to the follower, so the follower always takes the shortest arc,
+    // while its own output stays continuous. The previous target is unwrapped the same way
+    // to keep the target derivative free of 2 * PI jumps.
+
+    let x = y + wrap_angle(x - y);
+    let xp = x + wrap_angle(xp - x);
+
+    interpolation_step_float(k1, k2, k3, x, xp, y, yd, d)
+}
+
+#[inline]
+fn wrap_angle(angle: f32) -> f32 {
+    f32::atan2(angle.sin(), angle.cos())
+}
+
+#[inline]
+fn interpolation_step_euler_degrees(
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    x: Vector3,
+    xp: Vector3,
+    y: Vector3,
+    yd: Vector3,
+    d: f32,
+) -> (Vector3, Vector3, Vector3) {
+    // Same as the angle step, but for each of the Euler angles in degrees.
+
+    let x = y + wrap_degrees_vector3(x - y);
+    let xp = x + wrap_degrees_vector3(xp - x);
+
+    interpolation_step_vector3(k1, k2, k3, x, xp, y, yd, d)
+}
+
+#[inline]
+fn wrap_degrees_vector3(angles: Vector3) -> Vector3 {
+    Vector3::new(
+        wrap_degrees(angles.x),
+        wrap_degrees(angles.y),
+        wrap_degrees(angles.z),
+    )
+}
+
+/// Wraps an angle in degrees into [-180, 180).
+#[inline]
+fn wrap_degrees(angle: f32) -> f32 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+generate_default_interpolation_step!(interpolation_step_vector4, Vector4);
 generate_default_interpolation_step!(interpolation_step_vector3, Vector3);
 generate_default_interpolation_step!(interpolation_step_vector2, Vector2);
 generate_default_interpolation_step!(interpolation_step_float, f32);
 
+generate_rk4_interpolation_step!(interpolation_step_vector4_rk4, Vector4);
+generate_rk4_interpolation_step!(interpolation_step_vector3_rk4, Vector3);
+generate_rk4_interpolation_step!(interpolation_step_vector2_rk4, Vector2);
+generate_rk4_interpolation_step!(interpolation_step_float_rk4, f32);
+
+generate_analytic_interpolation_step!(interpolation_step_vector4_analytic, Vector4);
+generate_analytic_interpolation_step!(interpolation_step_vector3_analytic, Vector3);
+generate_analytic_interpolation_step!(interpolation_step_vector2_analytic, Vector2);
+generate_analytic_interpolation_step!(interpolation_step_float_analytic, f32);
+
 generate_systems_for_simple_types!(
     SecondOrderSystemVector3,
     Vector3,
     Vector3::ZERO,
-    interpolation_step_vector3
+    Vector3::ZERO,
+    interpolation_step_vector3,
+    rk4: interpolation_step_vector3_rk4,
+    analytic: interpolation_step_vector3_analytic,
+    anisotropic: 3
+);
+generate_systems_for_simple_types!(
+    SecondOrderSystemVector4,
+    Vector4,
+    Vector4::ZERO,
+    Vector4::ZERO,
+    interpolation_step_vector4,
+    rk4: interpolation_step_vector4_rk4,
+    analytic: interpolation_step_vector4_analytic
 );
 generate_systems_for_simple_types!(
     SecondOrderSystemVector2,
     Vector2,
     Vector2::ZERO,
-    interpolation_step_vector2
+    Vector2::ZERO,
+    interpolation_step_vector2,
+    rk4: interpolation_step_vector2_rk4,
+    analytic: interpolation_step_vector2_analytic,
+    anisotropic: 2
+);
+generate_systems_for_simple_types!(
+    SecondOrderSystemFloat,
+    f32,
+    0.0,
+    0.0,
+    interpolation_step_float,
+    rk4: interpolation_step_float_rk4,
+    analytic: interpolation_step_float_analytic
+);
+generate_systems_for_simple_types!(
+    SecondOrderSystemAngle,
+    f32,
+    0.0,
+    0.0,
+    interpolation_step_angle
+);
+generate_systems_for_simple_types!(
+    SecondOrderSystemEulerDegrees,
+    Vector3,
+    Vector3::ZERO,
+    Vector3::ZERO,
+    interpolation_step_euler_degrees
 );
-generate_systems_for_simple_types!(SecondOrderSystemFloat, f32, 0.0, interpolation_step_float);
 generate_systems_for_simple_types!(
     SecondOrderSystemQuaternion,
     Quaternion,
     Quaternion::default(),
+    // The derivative lives in the log space, where the zero quaternion means no rotation.
+    Quaternion::new(0.0, 0.0, 0.0, 0.0),
     interpolation_step_quaternion
 );
+generate_systems_for_simple_types!(
+    SecondOrderSystemColor,
+    Color,
+    Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+    Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+    interpolation_step_color,
+    space: ColorSpace, color_to_space, color_from_space
+);
+
+/// Steps a Transform3D by decomposing it into origin, rotation and scale,
+/// which are driven by their own systems sharing the same parameters.
+pub struct SecondOrderSystemTransform3D {
+    origin: SecondOrderSystemVector3,
+    rotation: SecondOrderSystemQuaternion,
+    scale: SecondOrderSystemVector3,
+}
+
+impl SecondOrderSystemTransform3D {
+    pub fn new(period: f32, damping: f32, response: f32) -> Self {
+        Self {
+            origin: SecondOrderSystemVector3::new(period, damping, response),
+            rotation: SecondOrderSystemQuaternion::new(period, damping, response),
+            scale: SecondOrderSystemVector3::new(period, damping, response),
+        }
+    }
+
+    /// Creates a system already at rest at the initial value.
+    pub fn with_initial(period: f32, damping: f32, response: f32, initial: Transform3D) -> Self {
+        let mut system = Self::new(period, damping, response);
+        system.snap_to(initial);
+        system
+    }
+
+    pub fn update_period(&mut self, period: f32) {
+        self.origin.update_period(period);
+        self.rotation.update_period(period);
+        self.scale.update_period(period);
+    }
+
+    pub fn update_damping(&mut self, damping: f32) {
+        self.origin.update_damping(damping);
+        self.rotation.update_damping(damping);
+        self.scale.update_damping(damping);
+    }
+
+    pub fn update_response(&mut self, response: f32) {
+        self.origin.update_response(response);
+        self.rotation.update_response(response);
+        self.scale.update_response(response);
+    }
+
+    pub fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.origin.update_params(period, damping, response);
+        self.rotation.update_params(period, damping, response);
+        self.scale.update_params(period, damping, response);
+    }
+
+    pub fn update_integration_method(&mut self, integration_method: IntegrationMethod) {
+        self.origin.update_integration_method(integration_method);
+        self.rotation.update_integration_method(integration_method);
+        self.scale.update_integration_method(integration_method);
+    }
+
+    pub fn update_analytic_when_static(&mut self, analytic_when_static: bool) {
+        self.origin
+            .update_analytic_when_static(analytic_when_static);
+        self.rotation
+            .update_analytic_when_static(analytic_when_static);
+        self.scale.update_analytic_when_static(analytic_when_static);
+    }
+
+    /// Caps the angular speed of the rotation, in radians per second. Zero removes the cap.
+    pub fn update_max_angular_speed(&mut self, max_angular_speed: f32) {
+        self.rotation.update_max_speed(max_angular_speed);
+    }
+
+    pub fn update_initial_values(&mut self, previous: Transform3D, current: Transform3D) {
+        let (previous_origin, previous_rotation, previous_scale) = decompose_transform_3d(previous);
+        let (current_origin, current_rotation, current_scale) = decompose_transform_3d(current);
+
+        self.origin
+            .update_initial_values(previous_origin, current_origin, Vector3::ZERO);
+        self.rotation.update_initial_values(
+            previous_rotation,
+            current_rotation,
+            SecondOrderSystemQuaternion::zero_derivative(),
+        );
+        self.scale
+            .update_initial_values(previous_scale, current_scale, Vector3::ZERO);
+    }
+
+    pub fn snap_to(&mut self, value: Transform3D) {
+        let (origin, rotation, scale) = decompose_transform_3d(value);
+
+        self.origin.snap_to(origin);
+        self.rotation.snap_to(rotation);
+        self.scale.snap_to(scale);
+    }
+
+    pub fn current(&self) -> Transform3D {
+        compose_transform_3d(
+            self.origin.current(),
+            self.rotation.current(),
+            self.scale.current(),
+        )
+    }
+
+    /// The velocities of the origin, the rotation and the scale.
+    pub fn velocity(&self) -> (Vector3, Vector3, Vector3) {
+        (
+            self.origin.velocity(),
+            self.rotation.velocity(),
+            self.scale.velocity(),
+        )
+    }
+
+    pub fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool {
+        self.origin.is_settled(position_epsilon, velocity_epsilon)
+            && self.rotation.is_settled(position_epsilon, velocity_epsilon)
+            && self.scale.is_settled(position_epsilon, velocity_epsilon)
+    }
+
+    pub fn take_recovered(&mut self) -> bool {
+        self.origin.take_recovered() | self.rotation.take_recovered() | self.scale.take_recovered()
+    }
+
+    #[inline]
+    pub fn update(&mut self, input: Transform3D, delta: f64) -> Transform3D {
+        let (origin, rotation, scale) = decompose_transform_3d(input);
+
+        let origin = self.origin.update(origin, delta);
+        let rotation = self.rotation.update(rotation, delta);
+        let scale = self.scale.update(scale, delta);
+
+        compose_transform_3d(origin, rotation, scale)
+    }
+}
+
+impl_second_order_system!(
+    SecondOrderSystemTransform3D,
+    Transform3D,
+    (Vector3, Vector3, Vector3)
+);
+
+/// Steps a Basis by decomposing it into rotation and scale, which are driven by
+/// their own systems sharing the same parameters. The shear of the basis is dropped,
+/// so a sheared input comes out as the closest rotation and scale without it.
+pub struct SecondOrderSystemBasis {
+    rotation: SecondOrderSystemQuaternion,
+    scale: SecondOrderSystemVector3,
+}
+
+impl SecondOrderSystemBasis {
+    pub fn new(period: f32, damping: f32, response: f32) -> Self {
+        Self {
+            rotation: SecondOrderSystemQuaternion::new(period, damping, response),
+            scale: SecondOrderSystemVector3::new(period, damping, response),
+        }
+    }
+
+    /// Creates a system already at rest at the initial value.
+    pub fn with_initial(period: f32, damping: f32, response: f32, initial: Basis) -> Self {
+        let mut system = Self::new(period, damping, response);
+        system.snap_to(initial);
+        system
+    }
+
+    pub fn update_period(&mut self, period: f32) {
+        self.rotation.update_period(period);
+        self.scale.update_period(period);
+    }
+
+    pub fn update_damping(&mut self, damping: f32) {
+        self.rotation.update_damping(damping);
+        self.scale.update_damping(damping);
+    }
+
+    pub fn update_response(&mut self, response: f32) {
+        self.rotation.update_response(response);
+        self.scale.update_response(response);
+    }
+
+    pub fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.rotation.update_params(period, damping, response);
+        self.scale.update_params(period, damping, response);
+    }
+
+    pub fn update_integration_method(&mut self, integration_method: IntegrationMethod) {
+        self.rotation.update_integration_method(integration_method);
+        self.scale.update_integration_method(integration_method);
+    }
+
+    pub fn update_analytic_when_static(&mut self, analytic_when_static: bool) {
+        self.rotation
+            .update_analytic_when_static(analytic_when_static);
+        self.scale.update_analytic_when_static(analytic_when_static);
+    }
+
+    /// Caps the angular speed of the rotation, in radians per second. Zero removes the cap.
+    pub fn update_max_angular_speed(&mut self, max_angular_speed: f32) {
+        self.rotation.update_max_speed(max_angular_speed);
+    }
+
+    pub fn update_initial_values(&mut self, previous: Basis, current: Basis) {
+        let (previous_rotation, previous_scale) = decompose_basis(previous);
+        let (current_rotation, current_scale) = decompose_basis(current);
+
+        self.rotation.update_initial_values(
+            previous_rotation,
+            current_rotation,
+            SecondOrderSystemQuaternion::zero_derivative(),
+        );
+        self.scale
+            .update_initial_values(previous_scale, current_scale, Vector3::ZERO);
+    }
+
+    pub fn snap_to(&mut self, value: Basis) {
+        let (rotation, scale) = decompose_basis(value);
+
+        self.rotation.snap_to(rotation);
+        self.scale.snap_to(scale);
+    }
+
+    pub fn current(&self) -> Basis {
+        compose_basis(self.rotation.current(), self.scale.current())
+    }
+
+    /// The velocities of the rotation and the scale.
+    pub fn velocity(&self) -> (Vector3, Vector3) {
+        (self.rotation.velocity(), self.scale.velocity())
+    }
+
+    pub fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool {
+        self.rotation.is_settled(position_epsilon, velocity_epsilon)
+            && self.scale.is_settled(position_epsilon, velocity_epsilon)
+    }
+
+    pub fn take_recovered(&mut self) -> bool {
+        self.rotation.take_recovered() | self.scale.take_recovered()
+    }
+
+    #[inline]
+    pub fn update(&mut self, input: Basis, delta: f64) -> Basis {
+        let (rotation, scale) = decompose_basis(input);
+
+        let rotation = self.rotation.update(rotation, delta);
+        let scale = self.scale.update(scale, delta);
+
+        compose_basis(rotation, scale)
+    }
+}
+
+impl_second_order_system!(SecondOrderSystemBasis, Basis, (Vector3, Vector3));
+
+/// Steps a Rect2 as its position and size, which are driven by their own systems
+/// sharing the same parameters.
+pub struct SecondOrderSystemRect2 {
+    position: SecondOrderSystemVector2,
+    size: SecondOrderSystemVector2,
+}
+
+impl SecondOrderSystemRect2 {
+    pub fn new(period: f32, damping: f32, response: f32) -> Self {
+        Self {
+            position: SecondOrderSystemVector2::new(period, damping, response),
+            size: SecondOrderSystemVector2::new(period, damping, response),
+        }
+    }
+
+    /// Creates a system already at rest at the initial value.
+    pub fn with_initial(period: f32, damping: f32, response: f32, initial: Rect2) -> Self {
+        let mut system = Self::new(period, damping, response);
+        system.snap_to(initial);
+        system
+    }
+
+    pub fn update_period(&mut self, period: f32) {
+        self.position.update_period(period);
+        self.size.update_period(period);
+    }
+
+    pub fn update_damping(&mut self, damping: f32) {
+        self.position.update_damping(damping);
+        self.size.update_damping(damping);
+    }
+
+    pub fn update_response(&mut self, response: f32) {
+        self.position.update_response(response);
+        self.size.update_response(response);
+    }
+
+    pub fn update_params(&mut self, period: f32, damping: f32, response: f32) {
+        self.position.update_params(period, damping, response);
+        self.size.update_params(period, damping, response);
+    }
+
+    pub fn update_integration_method(&mut self, integration_method: IntegrationMethod) {
+        self.position.update_integration_method(integration_method);
+        self.size.update_integration_method(integration_method);
+    }
+
+    pub fn update_analytic_when_static(&mut self, analytic_when_static: bool) {
+        self.position
+            .update_analytic_when_static(analytic_when_static);
+        self.size.update_analytic_when_static(analytic_when_static);
+    }
+
+    pub fn update_initial_values(&mut self, previous: Rect2, current: Rect2) {
+        self.position
+            .update_initial_values(previous.position, current.position, Vector2::ZERO);
+        self.size
+            .update_initial_values(previous.size, current.size, Vector2::ZERO);
+    }
+
+    pub fn snap_to(&mut self, value: Rect2) {
+        self.position.snap_to(value.position);
+        self.size.snap_to(value.size);
+    }
+
+    pub fn current(&self) -> Rect2 {
+        Rect2::new(self.position.current(), self.size.current())
+    }
+
+    /// The velocities of the position and the size.
+    pub fn velocity(&self) -> Rect2 {
+        Rect2::new(self.position.velocity(), self.size.velocity())
+    }
+
+    pub fn is_settled(&self, position_epsilon: f32, velocity_epsilon: f32) -> bool {
+        self.position.is_settled(position_epsilon, velocity_epsilon)
+            && self.size.is_settled(position_epsilon, velocity_epsilon)
+    }
+
+    pub fn take_recovered(&mut self) -> bool {
+        self.position.take_recovered() | self.size.take_recovered()
+    }
+
+    #[inline]
+    pub fn update(&mut self, input: Rect2, delta: f64) -> Rect2 {
+        let position = self.position.update(input.position, delta);
+        let size = self.size.update(input.size, delta);
+
+        Rect2::new(position, size)
+    }
+}
+
+impl_second_order_system!(SecondOrderSystemRect2, Rect2, Rect2);
+
+/// Splits the basis into rotation and scale. The shear is not representable by them
+/// and is dropped: the rotation is the orthonormalized basis, the scale is the length
+/// of its axes. A mirrored basis keeps the mirroring as a negative scale of the x axis.
+#[inline]
+pub fn decompose_basis(basis: Basis) -> (Quaternion, Vector3) {
+    let scale = basis.scale().abs();
+
+    // A mirrored basis is not a rotation, so its x axis is flipped back before converting it,
+    // and flipped again by the scale on recomposition.
+    if basis.determinant() < 0.0 {
+        let mirror = Vector3::new(-1.0, 1.0, 1.0);
+        let rotation = (basis * Basis::from_scale(mirror))
+            .orthonormalized()
+            .to_quat();
+        return (rotation, scale * mirror);
+    }
+
+    (basis.orthonormalized().to_quat(), scale)
+}
+
+#[inline]
+pub fn compose_basis(rotation: Quaternion, scale: Vector3) -> Basis {
+    // The scale is applied along the local axes before the rotation, so the basis has no shear.
+    Basis::from_quat(rotation.normalized()) * Basis::from_scale(scale)
+}
+
+#[inline]
+pub fn decompose_transform_3d(transform: Transform3D) -> (Vector3, Quaternion, Vector3) {
+    let (rotation, scale) = decompose_basis(transform.basis);
+    (transform.origin, rotation, scale)
+}
+
+#[inline]
+pub fn compose_transform_3d(
+    position: Vector3,
+    rotation: Quaternion,
+    scale: Vector3,
+) -> Transform3D {
+    Transform3D::new(compose_basis(rotation, scale), position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELTA: f64 = 1.0 / 60.0;
+
+    #[test]
+    fn zero_delta_keeps_the_state() {
+        let mut system = SecondOrderSystemFloat::new(1.0, 0.5, 2.0);
+        system.update(1.0, DELTA);
+        let state = system.state();
+
+        let output = system.update(2.0, 0.0);
+
+        assert!(output.is_finite());
+        assert_eq!(output, state.1);
+        assert_eq!(system.state(), state);
+    }
+
+    #[test]
+    fn zero_delta_keeps_the_rotation() {
+        let target = Quaternion::from_axis_angle(Vector3::UP, 1.0);
+        let mut system = SecondOrderSystemQuaternion::new(1.0, 0.5, 2.0);
+        system.update(target, DELTA);
+        let state = system.state();
+
+        let output = system.update(target, 0.0);
+
+        assert!(output.all_finite());
+        assert_eq!(output, state.1);
+        assert_eq!(system.state(), state);
+    }
+
+    #[test]
+    fn zero_period_snaps_to_the_input() {
+        let mut system = SecondOrderSystemFloat::new(0.0, 0.5, 2.0);
+
+        let (k1, k2, k3) = system.coefficients();
+        assert!(k1.is_finite() && k2.is_finite() && k3.is_finite());
+
+        assert_eq!(system.update(1.0, DELTA), 1.0);
+        assert_eq!(system.velocity(), 0.0);
+
+        system.update_period(0.0);
+        assert_eq!(system.update(-3.0, DELTA), -3.0);
+
+        assert_eq!(checked_period(0.0), (0.0, true));
+        assert_eq!(checked_period(f32::NAN), (0.0, true));
+        assert_eq!(checked_period(2.0), (2.0, false));
+    }
+
+    #[test]
+    fn tiny_period_keeps_finite_coefficients() {
+        let mut system = SecondOrderSystemFloat::new(1e-30, 0.5, 2.0);
+
+        let (k1, k2, k3) = system.coefficients();
+        assert!(k1.is_finite() && k2.is_finite() && k3.is_finite());
+
+        for _ in 0..60 {
+            assert!(system.update(1.0, DELTA).is_finite());
+        }
+    }
+
+    #[test]
+    fn quaternion_stays_normalized() {
+        let mut system = SecondOrderSystemQuaternion::new(2.0, 0.3, 2.0);
+
+        for step in 0..10_000 {
+            let time = step as f32 * DELTA as f32;
+            let axis = Vector3::new(time.sin(), 1.0, time.cos()).normalized();
+            let target = Quaternion::from_axis_angle(axis, 3.0 * time);
+
+            let output = system.update(target, DELTA);
+
+            assert!((output.length() - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn activation_at_the_target_does_not_rotate() {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 0.7);
+        let mut system = SecondOrderSystemQuaternion::new(1.0, 0.5, 2.0);
+        system.update_initial_values(
+            rotation,
+            rotation,
+            SecondOrderSystemQuaternion::zero_derivative(),
+        );
+
+        for _ in 0..10 {
+            assert!(system.update(rotation, DELTA).is_equal_approx(rotation));
+        }
+    }
+
+    /// A float system at rest at 0, with a static input of 1 and no response overshoot,
+    /// so its exact output is known.
+    fn step_system(period: f32, damping: f32) -> SecondOrderSystemFloat {
+        let mut system = SecondOrderSystemFloat::new(period, damping, 0.0);
+        system.update_initial_values(1.0, 0.0, 0.0);
+        system
+    }
+
+    /// The exact output of a system made by step_system after the time.
+    fn step_response(system: &SecondOrderSystemFloat, time: f32) -> f32 {
+        let (k1, k2, _) = system.coefficients();
+        let (a, _, _, _) = analytic_coefficients(k1, k2, time);
+        1.0 - a
+    }
+
+    #[test]
+    fn substeps_follow_a_stiff_system_closer() {
+        const FRAME: f64 = 1.0 / 20.0;
+        const SUBSTEPS: u32 = 8;
+
+        let mut single = step_system(5.0, 0.5);
+        let mut substepped = step_system(5.0, 0.5);
+        let (mut single_error, mut substepped_error) = (0.0_f32, 0.0_f32);
+
+        for frame in 1..=20 {
+            let reference = step_response(&single, frame as f32 * FRAME as f32);
+
+            let output = single.update(1.0, FRAME);
+            single_error = single_error.max((output - reference).abs());
+
+            let mut output = 0.0;
+            for _ in 0..SUBSTEPS {
+                output = substepped.update(1.0, FRAME / SUBSTEPS as f64);
+            }
+            substepped_error = substepped_error.max((output - reference).abs());
+        }
+
+        assert!(substepped_error < 0.05);
+        assert!(substepped_error < single_error);
+    }
+
+    #[test]
+    fn rk4_follows_the_analytic_response() {
+        let mut system = step_system(1.0, 0.3);
+        system.update_integration_method(IntegrationMethod::RK4);
+
+        for frame in 1..=120 {
+            let reference = step_response(&system, frame as f32 * DELTA as f32);
+            let output = system.update(1.0, DELTA);
+
+            assert!((output - reference).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn recovers_from_a_nan_input() {
+        let mut system = SecondOrderSystemVector3::new(1.0, 0.5, 2.0);
+        system.update(Vector3::new(f32::NAN, 0.0, 0.0), DELTA);
+
+        let target = Vector3::new(1.0, 2.0, 3.0);
+        let output = system.update(target, DELTA);
+
+        assert_eq!(output, target);
+        assert!(system.velocity().all_finite());
+        assert!(system.take_recovered());
+
+        // Reported once, even if the state breaks again.
+        system.update(Vector3::new(f32::NAN, 0.0, 0.0), DELTA);
+        assert_eq!(system.update(target, DELTA), target);
+        assert!(!system.take_recovered());
+    }
+
+    #[test]
+    fn mirrored_transform_round_trips() {
+        let rotation = Basis::from_quat(Quaternion::from_axis_angle(Vector3::UP, 0.5));
+        let transform = Transform3D::new(
+            rotation * Basis::from_scale(Vector3::new(-1.0, 1.0, 1.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+
+        let (position, rotation, scale) = decompose_transform_3d(transform);
+
+        assert!(scale.x < 0.0);
+        assert!(compose_transform_3d(position, rotation, scale).is_equal_approx(&transform));
+    }
+
+    #[test]
+    fn max_speed_limits_a_teleporting_target() {
+        const MAX_SPEED: f32 = 2.0;
+
+        let mut system = SecondOrderSystemVector2::new(5.0, 0.5, 2.0);
+        system.update_max_speed(MAX_SPEED);
+        let max_distance = MAX_SPEED * DELTA as f32 + 1e-5;
+
+        let mut previous = system.current();
+        for step in 0..600 {
+            let target = if step < 300 {
+                Vector2::new(100.0, 0.0)
+            } else {
+                Vector2::new(-100.0, 50.0)
+            };
+
+            let output = system.update(target, DELTA);
+
+            assert!(previous.distance_to(output) <= max_distance);
+            assert!(system.speed() <= MAX_SPEED + 1e-5);
+            previous = output;
+        }
+    }
+
+    /// How many steps a system made by step_system takes to settle at the input.
+    fn steps_to_settle(mut system: SecondOrderSystemFloat) -> u32 {
+        let mut steps = 0;
+        while !system.is_settled(1e-3, 1e-3) {
+            system.update(1.0, DELTA);
+            steps += 1;
+            assert!(steps < 100_000);
+        }
+
+        steps
+    }
+
+    #[test]
+    fn higher_frequency_settles_faster() {
+        // The period of the systems is their natural frequency in Hz.
+        let slow = steps_to_settle(step_system(0.5, 1.0));
+        let fast = steps_to_settle(step_system(5.0, 1.0));
+
+        assert!(fast < slow);
+    }
+
+    #[test]
+    fn initial_velocity_is_the_reported_velocity() {
+        let angular_velocity = Vector3::new(0.0, 3.0, 0.0);
+        let mut system = SecondOrderSystemQuaternion::new(1.0, 0.5, 2.0);
+        system.update_initial_values(
+            Quaternion::default(),
+            Quaternion::default(),
+            Quaternion::from_velocity(angular_velocity),
+        );
+
+        assert_eq!(system.velocity(), angular_velocity);
+    }
+}